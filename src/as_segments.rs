@@ -0,0 +1,170 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use super::RingBuffer;
+use super::{USegments, UVec, UVecMut};
+
+/// A source of values stored as at most two contiguous slices.
+///
+/// Functions that take `impl AsSegments<T>` accept slices, arrays, vectors, `VecDeque`s, `UVec`s
+/// and references to them without separate overloads.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use uvector::{AsSegments, UVec};
+///
+/// fn sum<S: AsSegments<i32> + ?Sized>(s: &S) -> i32 {
+///     s.as_uvec().iter().sum()
+/// }
+///
+/// # #[cfg(feature = "alloc")]
+/// # fn main() {
+/// let mut vd: VecDeque<i32> = (2..4).collect();
+/// vd.push_front(1);
+/// assert_eq!(sum(&vd), 6);
+/// assert_eq!(sum(&[1, 2, 3]), 6);
+/// assert_eq!(sum(&vec![1, 2]), 3);
+/// assert_eq!(UVec::from(&vd), [1, 2, 3]);
+/// # }
+/// # #[cfg(not(feature = "alloc"))]
+/// # fn main() {}
+/// ```
+pub trait AsSegments<T> {
+    /// Returns the values as two slices, the second slice is empty for contiguous sources.
+    fn segments(&self) -> (&[T], &[T]);
+    /// Returns a read-only view of the values.
+    fn as_uvec(&self) -> UVec<'_, T> {
+        UVec::new(self.segments())
+    }
+}
+
+impl<T, S: AsSegments<T> + ?Sized> AsSegments<T> for &S {
+    fn segments(&self) -> (&[T], &[T]) {
+        (**self).segments()
+    }
+}
+
+impl<T, S: AsSegments<T> + ?Sized> AsSegments<T> for &mut S {
+    fn segments(&self) -> (&[T], &[T]) {
+        (**self).segments()
+    }
+}
+
+impl<T> AsSegments<T> for [T] {
+    fn segments(&self) -> (&[T], &[T]) {
+        (self, &[])
+    }
+}
+
+impl<T, const N: usize> AsSegments<T> for [T; N] {
+    fn segments(&self) -> (&[T], &[T]) {
+        (self, &[])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsSegments<T> for Vec<T> {
+    fn segments(&self) -> (&[T], &[T]) {
+        (self, &[])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsSegments<T> for Box<[T]> {
+    fn segments(&self) -> (&[T], &[T]) {
+        (self, &[])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsSegments<T> for VecDeque<T> {
+    fn segments(&self) -> (&[T], &[T]) {
+        self.as_slices()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> AsSegments<T> for RingBuffer<T> {
+    fn segments(&self) -> (&[T], &[T]) {
+        self.view().s
+    }
+}
+
+impl<'a, T> AsSegments<T> for UVec<'a, T> {
+    fn segments(&self) -> (&[T], &[T]) {
+        self.s
+    }
+}
+
+impl<'a, T> AsSegments<T> for UVecMut<'a, T> {
+    fn segments(&self) -> (&[T], &[T]) {
+        UVecMut::as_uvec(self).s
+    }
+}
+
+impl<'a, T> AsSegments<T> for USegments<'a, T, 2> {
+    fn segments(&self) -> (&[T], &[T]) {
+        let [a, b] = USegments::segments(self);
+        (a, b)
+    }
+}
+
+impl<'a, T, S: AsSegments<T> + ?Sized> From<&'a S> for UVec<'a, T> {
+    fn from(s: &'a S) -> Self {
+        s.as_uvec()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn total<S: AsSegments<u32> + ?Sized>(s: &S) -> (usize, u32) {
+        let uv = s.as_uvec();
+        (uv.len(), uv.iter().sum())
+    }
+
+    #[test]
+    fn sources() {
+        assert_eq!(total(&[1, 2, 3]), (3, 6));
+        assert_eq!(total(&[1, 2, 3][1..]), (2, 5));
+        let uv = UVec::new((&[1, 2], &[3]));
+        assert_eq!(total(&uv), (3, 6));
+        assert_eq!(uv.segments(), (&[1, 2][..], &[3][..]));
+        assert_eq!(total(&&uv), (3, 6));
+        let us = USegments::from(uv);
+        assert_eq!(total(&us), (3, 6));
+        let mut data = [4, 5];
+        let (a, b) = data.split_at_mut(1);
+        assert_eq!(total(&UVecMut::new((a, b))), (2, 9));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn alloc_sources() {
+        let mut vd: VecDeque<u32> = (2..5).collect();
+        vd.push_front(1);
+        assert_eq!(total(&vd), (4, 10));
+        assert_eq!(UVec::from(&vd), [1, 2, 3, 4]);
+        assert!(UVec::new((&[1], &[2, 3, 4, 5])).starts_with(&vd));
+        assert_eq!(total(&vec![1, 2]), (2, 3));
+        let b: Box<[u32]> = vec![7].into_boxed_slice();
+        assert_eq!(UVec::from(&b), [7]);
+        let mut rb = RingBuffer::new(2);
+        rb.push_back_overwrite(1);
+        rb.push_back_overwrite(2);
+        rb.push_back_overwrite(3);
+        assert_eq!(total(&rb), (2, 5));
+    }
+}
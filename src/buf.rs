@@ -0,0 +1,174 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+#[cfg(feature = "std")]
+use std::io::IoSlice;
+
+use bytes::{Buf, Bytes};
+
+use super::UVec;
+
+/// A cursor over `UVec<u8>` implementing `bytes::Buf`.
+///
+/// Multi-byte reads like `get_u32` work across the point where the first slice ends.
+///
+/// `Buf::copy_to_bytes` always copies, as `Bytes` can't refer to borrowed data. Bytes can only
+/// be taken without copying from `'static` data using `split_to_bytes`.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate bytes;
+/// # extern crate uvector;
+/// use bytes::Buf;
+/// use uvector::{UVec, UVecBuf};
+///
+/// let mut buf = UVecBuf::new(UVec::new((&[0, 0, 1], &[2, 7, 0])));
+/// assert_eq!(buf.get_u32(), 0x0102);
+/// assert_eq!(buf.get_u16_le(), 7);
+/// assert!(!buf.has_remaining());
+/// ```
+#[derive(Debug, Clone)]
+pub struct UVecBuf<'a> {
+    uv: UVec<'a, u8>,
+}
+
+impl<'a> UVecBuf<'a> {
+    /// Constructs a new `UVecBuf` positioned at the start of the `UVec`
+    pub fn new(uv: UVec<'a, u8>) -> Self {
+        UVecBuf { uv }
+    }
+    /// Consumes the cursor, returning the unread part of the `UVec`.
+    pub fn into_inner(self) -> UVec<'a, u8> {
+        self.uv
+    }
+    /// Returns the unread part of the `UVec`.
+    pub fn get_ref(&self) -> &UVec<'a, u8> {
+        &self.uv
+    }
+    fn check_remaining(&self, cnt: usize) {
+        let len = self.uv.len();
+        assert!(
+            cnt <= len,
+            "cannot advance past `remaining`: {} <= {}",
+            cnt,
+            len
+        );
+    }
+}
+
+impl UVecBuf<'static> {
+    /// Consumes `len` bytes and returns them as `Bytes`. Unlike `copy_to_bytes` it doesn't copy
+    /// the data if the requested span is in a single slice, as static slices can be referred to
+    /// by `Bytes` directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than the number of remaining bytes.
+    pub fn split_to_bytes(&mut self, len: usize) -> Bytes {
+        self.check_remaining(len);
+        let (head, tail) = self.uv.split_at(len);
+        self.uv = tail;
+        match head.as_single_slice() {
+            Some(s) => Bytes::from_static(s),
+            None => Bytes::from(head.to_vec()),
+        }
+    }
+}
+
+impl<'a> From<UVec<'a, u8>> for UVecBuf<'a> {
+    fn from(uv: UVec<'a, u8>) -> Self {
+        UVecBuf::new(uv)
+    }
+}
+
+impl<'a> Buf for UVecBuf<'a> {
+    fn remaining(&self) -> usize {
+        self.uv.len()
+    }
+    /// Returns the unread part of the slice containing the current position.
+    fn chunk(&self) -> &[u8] {
+        let (a, b) = self.uv.s;
+        if a.is_empty() {
+            b
+        } else {
+            a
+        }
+    }
+    #[cfg(feature = "std")]
+    fn chunks_vectored<'b>(&'b self, dst: &mut [IoSlice<'b>]) -> usize {
+        let slices = self.uv.as_io_slices();
+        let n = slices.len().min(dst.len());
+        dst[..n].copy_from_slice(&slices[..n]);
+        n
+    }
+    fn advance(&mut self, cnt: usize) {
+        self.check_remaining(cnt);
+        self.uv = self.uv.range(cnt..);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get_across_seam() {
+        let data = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+        for mid in 0..data.len() + 1 {
+            let (a, b) = data.split_at(mid);
+            let mut buf = UVecBuf::new(UVec::new((a, b)));
+            let mut expected = &data[..];
+            assert_eq!(buf.get_u32(), expected.get_u32());
+            assert_eq!(buf.get_u64_le(), expected.get_u64_le());
+            assert_eq!(buf.remaining(), 2);
+            assert_eq!(buf.get_i16(), expected.get_i16());
+            assert!(!buf.has_remaining());
+            assert!(buf.try_get_u8().is_err());
+        }
+    }
+
+    #[test]
+    fn chunk_advance() {
+        let mut buf = UVecBuf::new(UVec::new((b"abc", b"de")));
+        assert_eq!(buf.chunk(), b"abc");
+        buf.advance(2);
+        assert_eq!(buf.chunk(), b"c");
+        buf.advance(1);
+        assert_eq!(buf.chunk(), b"de");
+        buf.advance(2);
+        assert_eq!(buf.chunk(), b"");
+        assert_eq!(buf.into_inner().len(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn chunks_vectored() {
+        let buf = UVecBuf::new(UVec::new((b"abc", b"de")));
+        let mut dst = [IoSlice::new(&[]); 3];
+        assert_eq!(buf.chunks_vectored(&mut dst), 2);
+        assert_eq!(&*dst[1], b"de");
+        let mut one = [IoSlice::new(&[])];
+        assert_eq!(buf.chunks_vectored(&mut one), 1);
+        assert_eq!(&*one[0], b"abc");
+    }
+
+    #[test]
+    fn copy_to_bytes() {
+        let mut buf = UVecBuf::new(UVec::new((b"abc", b"def")));
+        assert_eq!(buf.copy_to_bytes(2), &b"ab"[..]);
+        assert_eq!(buf.copy_to_bytes(2), &b"cd"[..]);
+        assert_eq!(buf.get_ref().len(), 2);
+        let mut buf = UVecBuf::new(UVec::new((b"abc", b"def")));
+        let head = buf.split_to_bytes(3);
+        assert_eq!(head.as_ptr(), b"abc".as_ptr());
+        assert_eq!(buf.split_to_bytes(3), &b"def"[..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot advance past `remaining`")]
+    fn advance_past_end() {
+        UVecBuf::new(UVec::new((b"ab", b"c"))).advance(4);
+    }
+}
@@ -0,0 +1,333 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use core::iter::FusedIterator;
+
+use super::UVec;
+
+/// An iterator over overlapping windows of a `UVec`, see `UVec::windows`
+#[derive(Debug)]
+pub struct Windows<'a, T: 'a> {
+    v: UVec<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> Windows<'a, T> {
+    pub(crate) fn new(v: UVec<'a, T>, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        Windows { v, size }
+    }
+}
+
+impl<'a, T> Clone for Windows<'a, T> {
+    fn clone(&self) -> Self {
+        Windows {
+            v: self.v,
+            size: self.size,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = UVec<'a, T>;
+    fn next(&mut self) -> Option<UVec<'a, T>> {
+        if self.size > self.v.len() {
+            return None;
+        }
+        let w = self.v.range(..self.size);
+        self.v = self.v.range(1..);
+        Some(w)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Windows<'a, T> {
+    fn next_back(&mut self) -> Option<UVec<'a, T>> {
+        let len = self.v.len();
+        if self.size > len {
+            return None;
+        }
+        let w = self.v.range(len - self.size..);
+        self.v = self.v.range(..len - 1);
+        Some(w)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Windows<'a, T> {
+    fn len(&self) -> usize {
+        (self.v.len() + 1).saturating_sub(self.size)
+    }
+}
+
+impl<'a, T> FusedIterator for Windows<'a, T> {}
+
+/// An iterator over a `UVec` in non-overlapping chunks, starting at the beginning of the vector,
+/// see `UVec::chunks`
+#[derive(Debug)]
+pub struct Chunks<'a, T: 'a> {
+    v: UVec<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> Chunks<'a, T> {
+    pub(crate) fn new(v: UVec<'a, T>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks { v, size }
+    }
+}
+
+impl<'a, T> Clone for Chunks<'a, T> {
+    fn clone(&self) -> Self {
+        Chunks {
+            v: self.v,
+            size: self.size,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = UVec<'a, T>;
+    fn next(&mut self) -> Option<UVec<'a, T>> {
+        if self.v.is_empty() {
+            return None;
+        }
+        let n = self.size.min(self.v.len());
+        let (chunk, rest) = self.v.split_at(n);
+        self.v = rest;
+        Some(chunk)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Chunks<'a, T> {
+    fn next_back(&mut self) -> Option<UVec<'a, T>> {
+        let len = self.v.len();
+        if len == 0 {
+            return None;
+        }
+        let n = match len % self.size {
+            0 => self.size,
+            rem => rem,
+        };
+        let (rest, chunk) = self.v.split_at(len - n);
+        self.v = rest;
+        Some(chunk)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Chunks<'a, T> {
+    fn len(&self) -> usize {
+        self.v.len().div_ceil(self.size)
+    }
+}
+
+impl<'a, T> FusedIterator for Chunks<'a, T> {}
+
+/// An iterator over a `UVec` in non-overlapping chunks of exactly the given size, starting at the
+/// beginning of the vector, see `UVec::chunks_exact`
+///
+/// The elements that do not fit into the last chunk can be retrieved with `remainder`.
+#[derive(Debug)]
+pub struct ChunksExact<'a, T: 'a> {
+    v: UVec<'a, T>,
+    rem: UVec<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> ChunksExact<'a, T> {
+    pub(crate) fn new(v: UVec<'a, T>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        let len = v.len() - v.len() % size;
+        let (v, rem) = v.split_at(len);
+        ChunksExact { v, rem, size }
+    }
+    /// Returns the elements at the end of the vector that do not fit into a whole chunk.
+    pub fn remainder(&self) -> UVec<'a, T> {
+        self.rem
+    }
+}
+
+impl<'a, T> Clone for ChunksExact<'a, T> {
+    fn clone(&self) -> Self {
+        ChunksExact {
+            v: self.v,
+            rem: self.rem,
+            size: self.size,
+        }
+    }
+}
+
+impl<'a, T> Iterator for ChunksExact<'a, T> {
+    type Item = UVec<'a, T>;
+    fn next(&mut self) -> Option<UVec<'a, T>> {
+        if self.v.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.v.split_at(self.size);
+        self.v = rest;
+        Some(chunk)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ChunksExact<'a, T> {
+    fn next_back(&mut self) -> Option<UVec<'a, T>> {
+        let len = self.v.len();
+        if len == 0 {
+            return None;
+        }
+        let (rest, chunk) = self.v.split_at(len - self.size);
+        self.v = rest;
+        Some(chunk)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ChunksExact<'a, T> {
+    fn len(&self) -> usize {
+        self.v.len() / self.size
+    }
+}
+
+impl<'a, T> FusedIterator for ChunksExact<'a, T> {}
+
+/// An iterator over a `UVec` in non-overlapping chunks, starting at the end of the vector, see
+/// `UVec::rchunks`
+#[derive(Debug)]
+pub struct RChunks<'a, T: 'a> {
+    v: UVec<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> RChunks<'a, T> {
+    pub(crate) fn new(v: UVec<'a, T>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        RChunks { v, size }
+    }
+}
+
+impl<'a, T> Clone for RChunks<'a, T> {
+    fn clone(&self) -> Self {
+        RChunks {
+            v: self.v,
+            size: self.size,
+        }
+    }
+}
+
+impl<'a, T> Iterator for RChunks<'a, T> {
+    type Item = UVec<'a, T>;
+    fn next(&mut self) -> Option<UVec<'a, T>> {
+        let len = self.v.len();
+        if len == 0 {
+            return None;
+        }
+        let n = self.size.min(len);
+        let (rest, chunk) = self.v.split_at(len - n);
+        self.v = rest;
+        Some(chunk)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RChunks<'a, T> {
+    fn next_back(&mut self) -> Option<UVec<'a, T>> {
+        let len = self.v.len();
+        if len == 0 {
+            return None;
+        }
+        let n = match len % self.size {
+            0 => self.size,
+            rem => rem,
+        };
+        let (chunk, rest) = self.v.split_at(n);
+        self.v = rest;
+        Some(chunk)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RChunks<'a, T> {
+    fn len(&self) -> usize {
+        self.v.len().div_ceil(self.size)
+    }
+}
+
+impl<'a, T> FusedIterator for RChunks<'a, T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::vec::Vec;
+
+    fn collect<I: Iterator<Item = UVec<'static, i32>>>(it: I) -> Vec<Vec<i32>> {
+        it.map(|uv| uv.iter().cloned().collect()).collect()
+    }
+
+    #[test]
+    fn windows() {
+        let uv = UVec::new((&[1, 2, 3], &[4, 5]));
+        let w = uv.windows(3);
+        assert_eq!(w.len(), 3);
+        assert_eq!(collect(w), [[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+        assert_eq!(collect(uv.windows(4).rev()), [[2, 3, 4, 5], [1, 2, 3, 4]]);
+        assert_eq!(uv.windows(5).len(), 1);
+        assert_eq!(uv.windows(6).len(), 0);
+        assert!(uv.windows(6).next().is_none());
+    }
+
+    #[test]
+    fn chunks() {
+        let uv = UVec::new((&[1, 2, 3], &[4, 5]));
+        let c = uv.chunks(2);
+        assert_eq!(c.len(), 3);
+        assert_eq!(collect(c), vec![vec![1, 2], vec![3, 4], vec![5]]);
+        assert_eq!(
+            collect(uv.chunks(2).rev()),
+            vec![vec![5], vec![3, 4], vec![1, 2]]
+        );
+        assert_eq!(collect(uv.chunks(5)), [[1, 2, 3, 4, 5]]);
+        assert_eq!(UVec::<i32>::empty().chunks(3).len(), 0);
+    }
+
+    #[test]
+    fn chunks_exact() {
+        let uv = UVec::new((&[1, 2, 3], &[4, 5]));
+        let c = uv.chunks_exact(2);
+        assert_eq!(c.len(), 2);
+        assert_eq!(c.remainder()[0], 5);
+        assert_eq!(collect(c.clone()), [[1, 2], [3, 4]]);
+        assert_eq!(collect(c.rev()), [[3, 4], [1, 2]]);
+        assert_eq!(uv.chunks_exact(5).remainder().len(), 0);
+        assert_eq!(uv.chunks_exact(6).len(), 0);
+    }
+
+    #[test]
+    fn rchunks() {
+        let uv = UVec::new((&[1, 2, 3], &[4, 5]));
+        let c = uv.rchunks(2);
+        assert_eq!(c.len(), 3);
+        assert_eq!(collect(c), vec![vec![4, 5], vec![2, 3], vec![1]]);
+        assert_eq!(
+            collect(uv.rchunks(2).rev()),
+            vec![vec![1], vec![2, 3], vec![4, 5]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_zero() {
+        UVec::new((&[1], &[2])).chunks(0);
+    }
+}
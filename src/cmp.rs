@@ -0,0 +1,221 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::UVec;
+
+impl<'a, T> UVec<'a, T> {
+    /// Compares the vector with a contiguous slice using slice comparison for both parts.
+    fn eq_slice<U>(&self, other: &[U]) -> bool
+    where
+        T: PartialEq<U>,
+    {
+        if self.len() != other.len() {
+            return false;
+        }
+        let (o1, o2) = other.split_at(self.s.0.len());
+        self.s.0 == o1 && self.s.1 == o2
+    }
+}
+
+impl<'a, 'b, T, U> PartialEq<UVec<'b, U>> for UVec<'a, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &UVec<'b, U>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, T: Eq> Eq for UVec<'a, T> {}
+
+impl<'a, T, U> PartialEq<[U]> for UVec<'a, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.eq_slice(other)
+    }
+}
+
+impl<'a, 'b, T, U> PartialEq<&'b [U]> for UVec<'a, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &&'b [U]) -> bool {
+        self.eq_slice(other)
+    }
+}
+
+impl<'a, T, U, const N: usize> PartialEq<[U; N]> for UVec<'a, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.eq_slice(other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T, U> PartialEq<Vec<U>> for UVec<'a, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.eq_slice(other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T, U> PartialEq<VecDeque<U>> for UVec<'a, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &VecDeque<U>) -> bool {
+        *self == UVec::new(other.as_slices())
+    }
+}
+
+impl<'a, T, U> PartialEq<UVec<'a, U>> for [T]
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &UVec<'a, U>) -> bool {
+        UVec::new((self, &[])) == *other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T, U> PartialEq<UVec<'a, U>> for Vec<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &UVec<'a, U>) -> bool {
+        UVec::new((self, &[])) == *other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T, U> PartialEq<UVec<'a, U>> for VecDeque<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &UVec<'a, U>) -> bool {
+        UVec::new(self.as_slices()) == *other
+    }
+}
+
+impl<'a, 'b, T: PartialOrd> PartialOrd<UVec<'b, T>> for UVec<'a, T> {
+    fn partial_cmp(&self, other: &UVec<'b, T>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, T: Ord> Ord for UVec<'a, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Hashes the length and then every element, so the hash doesn't depend on the split point
+/// with any hasher.
+impl<'a, T: Hash> Hash for UVec<'a, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for x in self.iter() {
+            x.hash(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash<H: Hasher + Default, V: Hash + ?Sized>(v: &V) -> u64 {
+        let mut h = H::default();
+        v.hash(&mut h);
+        h.finish()
+    }
+
+    /// A hasher mixing every `write` call as a unit, so the result depends on how the input is
+    /// split between the calls.
+    #[derive(Default)]
+    struct CallHasher(u64);
+
+    impl Hasher for CallHasher {
+        fn write(&mut self, bytes: &[u8]) {
+            let mut x = bytes.len() as u64;
+            for &b in bytes {
+                x = x.rotate_left(8) ^ u64::from(b);
+            }
+            self.0 = (self.0 ^ x).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        }
+        fn finish(&self) -> u64 {
+            self.0
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn eq() {
+        let a = UVec::new((&[1, 2, 3], &[4, 5]));
+        let b = UVec::new((&[1], &[2, 3, 4, 5]));
+        assert_eq!(a, b);
+        assert_ne!(a, b.range(1..));
+        assert_ne!(a, UVec::new((&[1], &[2, 3, 4, 6])));
+        assert_eq!(a, [1, 2, 3, 4, 5]);
+        assert_eq!(a, &[1, 2, 3, 4, 5][..]);
+        assert_eq!(a, vec![1, 2, 3, 4, 5]);
+        assert_eq!(vec![1, 2, 3, 4, 5], a);
+        assert_ne!(a, [1, 2, 3, 4]);
+        let mut vd: VecDeque<i32> = (3..6).collect();
+        vd.push_front(2);
+        vd.push_front(1);
+        assert_eq!(a, vd);
+        assert_eq!(vd, b);
+    }
+
+    #[test]
+    fn ord() {
+        let a = UVec::new((&[1, 2], &[3]));
+        let b = UVec::new((&[1], &[2, 4]));
+        assert!(a < b);
+        assert!(a.range(..2) < a);
+        assert_eq!(a.cmp(&UVec::new((&[], &[1, 2, 3]))), Ordering::Equal);
+        assert_eq!(b.cmp(&a), Ordering::Greater);
+    }
+
+    #[test]
+    fn hash_split_point() {
+        let v = [1u8, 2, 3, 4, 5];
+        let whole = UVec::new((&v[..], &[]));
+        for mid in 0..v.len() + 1 {
+            let (a, b) = v.split_at(mid);
+            let uv = UVec::new((a, b));
+            assert_eq!(
+                hash::<DefaultHasher, _>(&uv),
+                hash::<DefaultHasher, _>(&whole)
+            );
+            assert_eq!(hash::<CallHasher, _>(&uv), hash::<CallHasher, _>(&whole));
+        }
+        assert_ne!(
+            hash::<CallHasher, _>(&whole),
+            hash::<CallHasher, _>(&whole.range(1..))
+        );
+        let s = ["a", "bc", "d"];
+        assert_eq!(
+            hash::<CallHasher, _>(&UVec::new((&s[..1], &s[1..]))),
+            hash::<CallHasher, _>(&UVec::new((&s[..], &[])))
+        );
+    }
+}
@@ -0,0 +1,249 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use core::fmt;
+
+use super::UVec;
+
+/// The error returned when a cursor is asked for more values than remain in its vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorError {
+    /// Position of the cursor from the start of the vector
+    pub offset: usize,
+    /// Number of values requested
+    pub requested: usize,
+    /// Number of values remaining after `offset`
+    pub available: usize,
+}
+
+impl fmt::Display for CursorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "requested {} values at offset {} but only {} are available",
+            self.requested, self.offset, self.available
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CursorError {}
+
+/// A saved position of a `UCursor`, see `UCursor::checkpoint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Checkpoint(usize);
+
+/// A cursor walking through a `UVec` without copying its values.
+///
+/// All the returned views borrow from the underlying slices rather than from the cursor, and the
+/// cursor itself is `Copy`, so saving and restoring its state is cheap.
+///
+/// # Examples
+///
+/// ```
+/// use uvector::{UCursor, UVec};
+///
+/// let mut cur = UCursor::new(UVec::new((b"GET /in", b"dex HTTP/1.1")));
+/// let method = cur.take_while(|&b| b != b' ');
+/// assert_eq!(method, b"GET"[..]);
+/// cur.advance(1).unwrap();
+/// let start = cur.checkpoint();
+/// assert_eq!(cur.take_while(|&b| b != b' '), b"/index"[..]);
+/// cur.restore(start);
+/// assert_eq!(cur.peek(), Some(&b'/'));
+/// let err = cur.take(100).unwrap_err();
+/// assert_eq!((err.offset, err.available), (4, 15));
+/// ```
+#[derive(Debug)]
+pub struct UCursor<'a, T: 'a> {
+    uv: UVec<'a, T>,
+    pos: usize,
+}
+
+impl<'a, T> Clone for UCursor<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for UCursor<'a, T> {}
+
+impl<'a, T> UCursor<'a, T> {
+    /// Constructs a new `UCursor` positioned at the start of the `UVec`
+    pub fn new(uv: UVec<'a, T>) -> Self {
+        UCursor { uv, pos: 0 }
+    }
+    /// Returns the whole underlying vector.
+    pub fn get_ref(&self) -> UVec<'a, T> {
+        self.uv
+    }
+    /// Returns the position of the cursor from the start of the vector.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+    /// Returns the number of values after the current position.
+    pub fn remaining(&self) -> usize {
+        self.uv.len() - self.pos
+    }
+    /// Returns `true` if there are no values after the current position.
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+    /// Returns the values after the current position.
+    pub fn rest(&self) -> UVec<'a, T> {
+        self.uv.range(self.pos..)
+    }
+    /// Returns the value at the current position without advancing, or `None` if the cursor is
+    /// at the end.
+    pub fn peek(&self) -> Option<&'a T> {
+        self.uv.get(self.pos)
+    }
+    /// Returns `n` values starting at the current position without advancing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CursorError` if fewer than `n` values remain.
+    pub fn peek_n(&self, n: usize) -> Result<UVec<'a, T>, CursorError> {
+        self.check(n)?;
+        Ok(self.uv.range(self.pos..self.pos + n))
+    }
+    /// Moves the cursor `n` values forward.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CursorError` and leaves the cursor unchanged if fewer than `n` values remain.
+    pub fn advance(&mut self, n: usize) -> Result<(), CursorError> {
+        self.check(n)?;
+        self.pos += n;
+        Ok(())
+    }
+    /// Returns `n` values starting at the current position and moves the cursor past them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CursorError` and leaves the cursor unchanged if fewer than `n` values remain.
+    pub fn take(&mut self, n: usize) -> Result<UVec<'a, T>, CursorError> {
+        let uv = self.peek_n(n)?;
+        self.pos += n;
+        Ok(uv)
+    }
+    /// Returns the longest run of values starting at the current position that satisfy `pred`
+    /// and moves the cursor past them.
+    pub fn take_while<P>(&mut self, mut pred: P) -> UVec<'a, T>
+    where
+        P: FnMut(&'a T) -> bool,
+    {
+        let rest = self.rest();
+        let n = rest.position(|v| !pred(v)).unwrap_or(rest.len());
+        self.pos += n;
+        rest.range(..n)
+    }
+    /// Moves the cursor back to the start of the vector.
+    pub fn rewind(&mut self) {
+        self.pos = 0;
+    }
+    /// Saves the current position, so it can be returned to with `restore`.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.pos)
+    }
+    /// Moves the cursor to a position saved by `checkpoint`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint is past the end of the vector, which may only happen if it was
+    /// made by a cursor over a longer vector.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        assert!(
+            checkpoint.0 <= self.uv.len(),
+            "checkpoint {} is past the end of the vector of length {}",
+            checkpoint.0,
+            self.uv.len()
+        );
+        self.pos = checkpoint.0;
+    }
+    fn check(&self, n: usize) -> Result<(), CursorError> {
+        let available = self.remaining();
+        if n > available {
+            return Err(CursorError {
+                offset: self.pos,
+                requested: n,
+                available,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T> From<UVec<'a, T>> for UCursor<'a, T> {
+    fn from(uv: UVec<'a, T>) -> Self {
+        UCursor::new(uv)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn walk() {
+        let data = [1, 2, 3, 4, 5, 6];
+        for mid in 0..data.len() + 1 {
+            let (a, b) = data.split_at(mid);
+            let mut cur = UCursor::new(UVec::new((a, b)));
+            assert_eq!(cur.peek(), Some(&1));
+            assert_eq!(cur.peek_n(2).unwrap(), [1, 2]);
+            assert_eq!(cur.take(3).unwrap(), [1, 2, 3]);
+            assert_eq!(cur.position(), 3);
+            let saved = cur;
+            assert_eq!(cur.take_while(|&v| v < 5), [4]);
+            assert!(cur.take_while(|&v| v < 5).is_empty());
+            cur.advance(2).unwrap();
+            assert!(cur.is_empty());
+            assert_eq!(cur.peek(), None);
+            assert_eq!(saved.rest(), [4, 5, 6]);
+            cur.rewind();
+            assert_eq!(cur.remaining(), 6);
+            assert_eq!(cur.take_while(|_| true), data);
+        }
+    }
+
+    #[test]
+    fn checkpoint() {
+        let mut cur = UCursor::from(UVec::new((&[1, 2], &[3])));
+        cur.advance(1).unwrap();
+        let cp = cur.checkpoint();
+        cur.advance(2).unwrap();
+        assert!(cur.checkpoint() > cp);
+        cur.restore(cp);
+        assert_eq!(cur.rest(), [2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "checkpoint 3 is past the end of the vector of length 1")]
+    fn restore_foreign() {
+        let mut long = UCursor::new(UVec::new((&[1, 2, 3], &[])));
+        long.advance(3).unwrap();
+        UCursor::new(UVec::new((&[1], &[]))).restore(long.checkpoint());
+    }
+
+    #[test]
+    fn exhausted() {
+        let mut cur = UCursor::new(UVec::new((&[1, 2], &[3])));
+        cur.advance(2).unwrap();
+        let err = CursorError {
+            offset: 2,
+            requested: 2,
+            available: 1,
+        };
+        assert_eq!(cur.take(2), Err(err));
+        assert_eq!(cur.peek_n(2), Err(err));
+        assert_eq!(cur.advance(2), Err(err));
+        assert_eq!(cur.position(), 2);
+        assert_eq!(
+            err.to_string(),
+            "requested 2 values at offset 2 but only 1 are available"
+        );
+    }
+}
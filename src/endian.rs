@@ -0,0 +1,108 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use super::UVec;
+
+macro_rules! read_num {
+    ($($t:ident: $le:ident, $be:ident;)*) => {
+        $(
+            #[doc = concat!("Reads a little-endian `", stringify!($t), "` at `offset`, returns `None`")]
+            #[doc = "if it is not contained within the vector."]
+            pub fn $le(&self, offset: usize) -> Option<$t> {
+                self.read_array(offset).map($t::from_le_bytes)
+            }
+            #[doc = concat!("Reads a big-endian `", stringify!($t), "` at `offset`, returns `None`")]
+            #[doc = "if it is not contained within the vector."]
+            pub fn $be(&self, offset: usize) -> Option<$t> {
+                self.read_array(offset).map($t::from_be_bytes)
+            }
+        )*
+    };
+}
+
+impl<'a> UVec<'a, u8> {
+    /// Reads `N` bytes at `offset` into an array, returns `None` if the bytes are not contained
+    /// within the vector. The bytes may be split between the two slices.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((&[0x12, 0x34], &[0x56, 0x78]));
+    /// assert_eq!(uv.read_array::<3>(1), Some([0x34, 0x56, 0x78]));
+    /// assert_eq!(uv.read_u16_be(1), Some(0x3456));
+    /// assert_eq!(uv.read_u32_le(0), Some(0x78563412));
+    /// assert_eq!(uv.read_u32_le(1), None);
+    /// ```
+    pub fn read_array<const N: usize>(&self, offset: usize) -> Option<[u8; N]> {
+        let end = offset.checked_add(N)?;
+        if end > self.len() {
+            return None;
+        }
+        let (a, b) = self.s;
+        let mut buf = [0u8; N];
+        if end <= a.len() {
+            buf.copy_from_slice(&a[offset..end]);
+        } else if offset >= a.len() {
+            buf.copy_from_slice(&b[offset - a.len()..end - a.len()]);
+        } else {
+            let (b1, b2) = buf.split_at_mut(a.len() - offset);
+            b1.copy_from_slice(&a[offset..]);
+            b2.copy_from_slice(&b[..b2.len()]);
+        }
+        Some(buf)
+    }
+
+    read_num! {
+        u16: read_u16_le, read_u16_be;
+        i16: read_i16_le, read_i16_be;
+        u32: read_u32_le, read_u32_be;
+        i32: read_i32_le, read_i32_be;
+        u64: read_u64_le, read_u64_be;
+        i64: read_i64_le, read_i64_be;
+        u128: read_u128_le, read_u128_be;
+        i128: read_i128_le, read_i128_be;
+        f32: read_f32_le, read_f32_be;
+        f64: read_f64_le, read_f64_be;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_array() {
+        let data = [1u8, 2, 3, 4, 5, 6];
+        for mid in 0..data.len() + 1 {
+            let (a, b) = data.split_at(mid);
+            let uv = UVec::new((a, b));
+            for offset in 0..data.len() + 1 {
+                let expected = data.get(offset..offset + 3).map(|s| [s[0], s[1], s[2]]);
+                assert_eq!(uv.read_array::<3>(offset), expected);
+            }
+            assert_eq!(uv.read_array::<0>(6), Some([]));
+            assert_eq!(uv.read_array::<0>(7), None);
+            assert_eq!(uv.read_array::<6>(0), Some(data));
+            assert_eq!(uv.read_array::<1>(usize::MAX), None);
+        }
+    }
+
+    #[test]
+    fn read_num() {
+        let data = [0x80u8, 1, 2, 3, 4, 5, 6, 7, 8];
+        for mid in 0..data.len() + 1 {
+            let (a, b) = data.split_at(mid);
+            let uv = UVec::new((a, b));
+            assert_eq!(uv.read_u16_le(0), Some(0x0180));
+            assert_eq!(uv.read_i16_be(0), Some(-0x7fff));
+            assert_eq!(uv.read_u32_be(1), Some(0x01020304));
+            assert_eq!(uv.read_i32_le(5), Some(0x08070605));
+            assert_eq!(uv.read_u64_be(1), Some(0x0102030405060708));
+            assert_eq!(uv.read_i64_le(0), Some(0x0706050403020180));
+            assert_eq!(uv.read_u64_le(2), None);
+            assert_eq!(uv.read_u128_be(0), None);
+            assert_eq!(uv.read_f32_be(1), Some(f32::from_bits(0x01020304)));
+            assert_eq!(uv.read_f64_le(1), Some(f64::from_bits(0x0807060504030201)));
+        }
+    }
+}
@@ -0,0 +1,335 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use core::cmp;
+use core::ops::{Deref, RangeBounds};
+use std::io::{self, BufRead, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
+
+use super::UVec;
+
+/// A cursor over `UVec<u8>` implementing `Read`, `BufRead` and `Seek`.
+///
+/// It allows to feed the content of a `VecDeque<u8>` into readers without copying it into a
+/// contiguous buffer first.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use std::io::{BufRead, Read};
+/// use uvector::{UVec, UVecReader};
+///
+/// let mut vd: VecDeque<u8> = VecDeque::new();
+/// vd.extend(b"world\n");
+/// for &b in b"hello ".iter().rev() {
+///     vd.push_front(b);
+/// }
+/// let mut rd = UVecReader::new(UVec::new(vd.as_slices()));
+/// let mut line = String::new();
+/// rd.read_line(&mut line).unwrap();
+/// assert_eq!(line, "hello world\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct UVecReader<'a> {
+    uv: UVec<'a, u8>,
+    pos: u64,
+}
+
+impl<'a> UVecReader<'a> {
+    /// Constructs a new `UVecReader` positioned at the start of the `UVec`
+    pub fn new(uv: UVec<'a, u8>) -> Self {
+        UVecReader { uv, pos: 0 }
+    }
+    /// Consumes the reader, returning the underlying `UVec`.
+    pub fn into_inner(self) -> UVec<'a, u8> {
+        self.uv
+    }
+    /// Returns a reference to the underlying `UVec`.
+    pub fn get_ref(&self) -> &UVec<'a, u8> {
+        &self.uv
+    }
+    /// Returns the current position of the reader.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+    /// Sets the position of the reader. The position may be past the end of the data, in which
+    /// case reads return no bytes.
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+    /// Returns the unread part of the slice containing the current position.
+    fn chunk(&self) -> &'a [u8] {
+        let (s1, s2) = self.uv.s;
+        let pos = cmp::min(self.pos, self.uv.len() as u64) as usize;
+        if pos < s1.len() {
+            &s1[pos..]
+        } else {
+            &s2[pos - s1.len()..]
+        }
+    }
+}
+
+impl<'a> Read for UVecReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut n = 0;
+        while n < buf.len() {
+            let chunk = self.chunk();
+            if chunk.is_empty() {
+                break;
+            }
+            let k = cmp::min(chunk.len(), buf.len() - n);
+            buf[n..n + k].copy_from_slice(&chunk[..k]);
+            self.consume(k);
+            n += k;
+        }
+        Ok(n)
+    }
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        let mut n = 0;
+        for buf in bufs {
+            let k = self.read(buf)?;
+            n += k;
+            if k < buf.len() {
+                break;
+            }
+        }
+        Ok(n)
+    }
+}
+
+impl<'a> BufRead for UVecReader<'a> {
+    /// Returns the rest of the slice containing the current position. If the unread data
+    /// continues in the second slice it will be returned by the next call after `consume`.
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.chunk())
+    }
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt as u64;
+    }
+}
+
+impl<'a> Seek for UVecReader<'a> {
+    fn seek(&mut self, style: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match style {
+            SeekFrom::Start(n) => {
+                self.pos = n;
+                return Ok(n);
+            }
+            SeekFrom::End(n) => (self.uv.len() as u64, n),
+            SeekFrom::Current(n) => (self.pos, n),
+        };
+        match base.checked_add_signed(offset) {
+            Some(n) => {
+                self.pos = n;
+                Ok(n)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+impl<'a> UVec<'a, u8> {
+    /// Returns the content of the vector as `IoSlice`s for vectored writes. Empty slices are
+    /// skipped, so the result contains from 0 to 2 elements.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// use std::io::Write;
+    ///
+    /// let uv = UVec::new((b"hello ", b"world"));
+    /// let mut out = Vec::new();
+    /// let n = out.write_vectored(&uv.as_io_slices()).unwrap();
+    /// assert_eq!(n, 11);
+    /// assert_eq!(uv.range(6..).as_io_slices().len(), 1);
+    /// ```
+    pub fn as_io_slices(&self) -> IoSlices<'a> {
+        let (a, b) = self.s;
+        let mut slices = IoSlices {
+            s: [IoSlice::new(&[]), IoSlice::new(&[])],
+            n: 0,
+        };
+        for part in [a, b] {
+            if !part.is_empty() {
+                slices.s[slices.n] = IoSlice::new(part);
+                slices.n += 1;
+            }
+        }
+        slices
+    }
+    /// Writes the whole content of the vector into `w` using vectored writes. Partial writes are
+    /// continued from the position where the previous write stopped, writes failing with
+    /// `ErrorKind::Interrupted` are retried.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error other than `ErrorKind::Interrupted` returned by `w`, or an error
+    /// of `ErrorKind::WriteZero` kind if `w` accepts no data.
+    pub fn write_all_vectored_to<W: Write + ?Sized>(&self, w: &mut W) -> io::Result<()> {
+        let len = self.len();
+        let mut pos = 0;
+        while pos < len {
+            match w.write_vectored(&self.range(pos..).as_io_slices()) {
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ))
+                }
+                Ok(n) => pos += n,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+    /// Writes the specified range of the vector into `w`, see `write_all_vectored_to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified range is not contained within the vector.
+    pub fn write_range_all_vectored_to<R, W>(&self, range: R, w: &mut W) -> io::Result<()>
+    where
+        R: RangeBounds<usize>,
+        W: Write + ?Sized,
+    {
+        self.range(range).write_all_vectored_to(w)
+    }
+}
+
+/// Up to two `IoSlice`s referring to the content of a `UVec<u8>`, see `UVec::as_io_slices`
+///
+/// Dereferences to `[IoSlice]`, so it can be passed directly to `Write::write_vectored`.
+#[derive(Debug, Clone, Copy)]
+pub struct IoSlices<'a> {
+    s: [IoSlice<'a>; 2],
+    n: usize,
+}
+
+impl<'a> Deref for IoSlices<'a> {
+    type Target = [IoSlice<'a>];
+    fn deref(&self) -> &[IoSlice<'a>] {
+        &self.s[..self.n]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[test]
+    fn read() {
+        let mut rd = UVecReader::new(UVec::new((b"abc", b"defg")));
+        let mut buf = [0u8; 2];
+        assert_eq!(rd.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf, b"ab");
+        assert_eq!(rd.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf, b"cd");
+        let mut rest = Vec::new();
+        rd.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"efg");
+        assert_eq!(rd.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn read_vectored() {
+        let mut rd = UVecReader::new(UVec::new((b"abc", b"defg")));
+        let (mut a, mut b) = ([0u8; 2], [0u8; 4]);
+        let n = rd
+            .read_vectored(&mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)])
+            .unwrap();
+        assert_eq!(n, 6);
+        assert_eq!(&a, b"ab");
+        assert_eq!(&b, b"cdef");
+        assert_eq!(rd.position(), 6);
+    }
+
+    #[test]
+    fn buf_read() {
+        let mut rd = UVecReader::new(UVec::new((b"ab", b"c\nd")));
+        assert_eq!(rd.fill_buf().unwrap(), b"ab");
+        rd.consume(1);
+        assert_eq!(rd.fill_buf().unwrap(), b"b");
+        rd.consume(1);
+        assert_eq!(rd.fill_buf().unwrap(), b"c\nd");
+        let mut rd = UVecReader::new(UVec::new((b"ab", b"c\nd")));
+        let lines: Vec<String> = rd.by_ref().lines().map(|l| l.unwrap()).collect();
+        assert_eq!(lines, ["abc", "d"]);
+    }
+
+    /// Writer accepting at most 3 bytes per call and failing every other call with
+    /// `ErrorKind::Interrupted`
+    struct Trickle {
+        out: Vec<u8>,
+        calls: usize,
+    }
+
+    impl Write for Trickle {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.write_vectored(&[IoSlice::new(buf)])
+        }
+        fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+            self.calls += 1;
+            if self.calls.is_multiple_of(2) {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            let mut n = 0;
+            for b in bufs.iter().flat_map(|s| s.iter()).take(3) {
+                self.out.push(*b);
+                n += 1;
+            }
+            Ok(n)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn io_slices() {
+        let uv = UVec::new((b"ab", b"cde"));
+        let slices = uv.as_io_slices();
+        assert_eq!(slices.len(), 2);
+        assert_eq!(&*slices[0], b"ab");
+        assert_eq!(&*slices[1], b"cde");
+        assert_eq!(&*uv.range(1..2).as_io_slices()[0], b"b");
+        assert_eq!(&*uv.range(2..).as_io_slices()[0], b"cde");
+        assert!(UVec::<u8>::empty().as_io_slices().is_empty());
+    }
+
+    #[test]
+    fn write_all_vectored() {
+        let uv = UVec::new((b"hello ", b"world"));
+        let mut w = Trickle {
+            out: Vec::new(),
+            calls: 0,
+        };
+        uv.write_all_vectored_to(&mut w).unwrap();
+        assert_eq!(w.out, b"hello world");
+        assert_eq!(w.calls, 7);
+        w.out.clear();
+        uv.write_range_all_vectored_to(4..8, &mut w).unwrap();
+        assert_eq!(w.out, b"o wo");
+        let mut full = [0u8; 4];
+        let err = uv.write_all_vectored_to(&mut &mut full[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(&full, b"hell");
+    }
+
+    #[test]
+    fn seek() {
+        let mut rd = UVecReader::new(UVec::new((b"abc", b"def")));
+        assert_eq!(rd.seek(SeekFrom::End(-2)).unwrap(), 4);
+        assert_eq!(rd.fill_buf().unwrap(), b"ef");
+        assert_eq!(rd.seek(SeekFrom::Current(-3)).unwrap(), 1);
+        assert_eq!(rd.fill_buf().unwrap(), b"bc");
+        assert!(rd.seek(SeekFrom::Current(-2)).is_err());
+        assert_eq!(rd.seek(SeekFrom::Start(10)).unwrap(), 10);
+        assert_eq!(rd.fill_buf().unwrap(), b"");
+    }
+}
@@ -3,9 +3,99 @@
 // Licensed under the MIT license see LICENSE file
 
 //! Allows access two read-only slices as a single vector.
-use std::ops::Index;
-use std::iter::Iterator;
-use std::iter::IntoIterator;
+//!
+//! The crate is `no_std`. The `alloc` feature enables methods that allocate and the types that
+//! own their storage, the `std` feature (enabled by default) adds `std::io` support. The `serde`
+//! feature implements `Serialize` for the vector types and `Deserialize` for `RingBuffer`, the
+//! `rayon` feature adds parallel iterators, the `bytes` feature provides `UVecBuf` implementing
+//! `bytes::Buf`, the `mirror` feature adds `MirroredRing` on Linux, and the `nom` feature
+//! implements the input traits of `nom` 7 for `UVec<u8>`.
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+#[macro_use]
+extern crate std;
+
+#[cfg(feature = "bytes")]
+extern crate bytes;
+#[cfg(all(feature = "mirror", target_os = "linux"))]
+extern crate libc;
+#[cfg(loom)]
+extern crate loom;
+extern crate memchr;
+#[cfg(feature = "nom")]
+extern crate nom;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_test;
+
+use core::iter::IntoIterator;
+use core::iter::{FusedIterator, Iterator};
+use core::ops::{Index, Range, RangeBounds};
+use core::slice;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+mod as_segments;
+#[cfg(feature = "bytes")]
+mod buf;
+mod chunks;
+mod cmp;
+mod cursor;
+mod endian;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(all(feature = "mirror", target_os = "linux"))]
+mod mirror;
+mod mutable;
+#[cfg(feature = "nom")]
+mod nom_impl;
+#[cfg(feature = "rayon")]
+mod par;
+mod range;
+#[cfg(feature = "alloc")]
+mod ring;
+mod search;
+mod segments;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod sort;
+#[cfg(feature = "alloc")]
+mod spsc;
+mod ustr;
+
+pub use as_segments::AsSegments;
+#[cfg(feature = "bytes")]
+pub use buf::UVecBuf;
+pub use chunks::{Chunks, ChunksExact, RChunks, Windows};
+pub use cursor::{Checkpoint, CursorError, UCursor};
+#[cfg(feature = "std")]
+pub use io::{IoSlices, UVecReader};
+#[cfg(all(feature = "mirror", target_os = "linux"))]
+pub use mirror::MirroredRing;
+pub use mutable::{IterMut, UVecMut};
+#[cfg(feature = "rayon")]
+pub use par::{ParChunks, ParIter};
+pub use range::RangeError;
+#[cfg(feature = "alloc")]
+pub use ring::RingBuffer;
+pub use search::FindIter;
+pub use segments::{SegmentsIter, USegments};
+#[cfg(feature = "serde")]
+pub use serde_impl::Bytes;
+#[cfg(feature = "alloc")]
+pub use spsc::{spsc, Consumer, Producer};
+pub use ustr::{CharIndices, Chars, Lines, Split, UStr, Utf8Error};
 
 /// Read-only array type allowing access two slices as a single continuous vector.
 ///
@@ -18,7 +108,7 @@ use std::iter::IntoIterator;
 /// // Return sum of the first 3 numbers in VecDeque
 /// fn head3_sum(vd: &VecDeque<i32>) -> i32 {
 ///     let uv = UVec::new(vd.as_slices());
-///     uv.range(0,3).iter().fold(0, |sum, x| sum + x)
+///     uv.range(0..3).iter().fold(0, |sum, x| sum + x)
 /// }
 ///
 /// fn main() {
@@ -52,13 +142,15 @@ use std::iter::IntoIterator;
 ///
 /// # Ranges
 ///
-/// You can get a subset of values using `range` method. It returns a new `UVec` which contains
-/// only specified range of values:
+/// You can get a subset of values using `range` method. It accepts any kind of range (`a..b`,
+/// `a..`, `..=b` and so on) and returns a new `UVec` which contains only specified range of
+/// values. Unlike slices, `UVec` can not be indexed with a range, as `Index` has to return a
+/// reference into existing data:
 ///
 /// ```
 /// # use uvector::UVec;
 /// let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
-/// let sub = uv.range(2, 4); // that will only contain [3, 4]
+/// let sub = uv.range(2..4); // that will only contain [3, 4]
 /// assert_eq!(uv[2], sub[0]);
 /// assert_eq!(uv[3], sub[1]);
 /// ```
@@ -102,31 +194,291 @@ impl<'a, T> UVec<'a, T> {
     pub fn len(&self) -> usize {
         self.s.0.len() + self.s.1.len()
     }
+    /// Returns `true` if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.s.0.is_empty() && self.s.1.is_empty()
+    }
     /// Returns iterator over `UVec`
-    pub fn iter(&self) -> Iter<T> {
-        Iter { pos: 0, s: self.s }
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            a: self.s.0.iter(),
+            b: self.s.1.iter(),
+        }
+    }
+    /// Returns the content of the vector as a single slice if it is not split between two
+    /// slices, i.e. one of the slices is empty. Note that a `UVec` returned by `range` only
+    /// refers to the slices the range lies in.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
+    /// assert_eq!(uv.as_single_slice(), None);
+    /// assert_eq!(uv.range(3..).as_single_slice(), Some(&[4, 5, 6][..]));
+    /// ```
+    pub fn as_single_slice(&self) -> Option<&'a [T]> {
+        if self.s.1.is_empty() {
+            Some(self.s.0)
+        } else if self.s.0.is_empty() {
+            Some(self.s.1)
+        } else {
+            None
+        }
+    }
+    /// Copies all elements of the vector into `dst`. Use `range` to copy only a part of the
+    /// vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` has a different length than the vector.
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            self.len(),
+            dst.len(),
+            "destination and source slices have different lengths"
+        );
+        let (d1, d2) = dst.split_at_mut(self.s.0.len());
+        d1.copy_from_slice(self.s.0);
+        d2.copy_from_slice(self.s.1);
+    }
+    /// Copies the vector into a new `Vec`.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut v = Vec::with_capacity(self.len());
+        v.extend_from_slice(self.s.0);
+        v.extend_from_slice(self.s.1);
+        v
+    }
+    /// Returns the content of the vector as a single slice, only allocating a new `Vec` if the
+    /// content is actually split between two slices.
+    ///
+    /// ```
+    /// # use std::borrow::Cow;
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
+    /// assert!(matches!(uv.range(1..3).to_contiguous(), Cow::Borrowed(&[2, 3])));
+    /// assert_eq!(uv.range(1..5).to_contiguous().into_owned(), [2, 3, 4, 5]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_contiguous(&self) -> Cow<'a, [T]>
+    where
+        T: Clone,
+    {
+        match self.as_single_slice() {
+            Some(s) => Cow::Borrowed(s),
+            None => Cow::Owned(self.to_vec()),
+        }
+    }
+    /// Returns an iterator over all contiguous windows of length `size`. The windows overlap and
+    /// may span both slices.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((&[1, 2], &[3, 4]));
+    /// let sums: Vec<i32> = uv.windows(2).map(|w| w.iter().sum()).collect();
+    /// assert_eq!(sums, [3, 5, 7]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn windows(&self, size: usize) -> Windows<'a, T> {
+        Windows::new(*self, size)
+    }
+    /// Returns an iterator over `size` elements of the vector at a time, starting at the
+    /// beginning. The last chunk will be shorter if `size` does not divide the length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunks(&self, size: usize) -> Chunks<'a, T> {
+        Chunks::new(*self, size)
+    }
+    /// Returns an iterator over `size` elements of the vector at a time, starting at the
+    /// beginning. The elements that do not fit into a whole chunk are available from the
+    /// `remainder` method of the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunks_exact(&self, size: usize) -> ChunksExact<'a, T> {
+        ChunksExact::new(*self, size)
+    }
+    /// Returns an iterator over `size` elements of the vector at a time, starting at the end.
+    /// The last chunk will be shorter if `size` does not divide the length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn rchunks(&self, size: usize) -> RChunks<'a, T> {
+        RChunks::new(*self, size)
     }
     /// Returns a new UVec that only includes the values from the specified range.
     ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
+    /// assert_eq!(uv.range(1..3).len(), 2);
+    /// assert_eq!(uv.range(..=3).len(), 4);
+    /// assert_eq!(uv.range(4..).len(), 2);
+    /// ```
+    ///
     /// # Panics
     ///
-    /// Panics if the specified range is not contained within the `UVec`
-    pub fn range(&self, start: usize, end: usize) -> Self {
+    /// Panics if the specified range is not contained within the `UVec`, with the same message as
+    /// indexing a slice would.
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        match self.try_range(range) {
+            Ok(uv) => uv,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    /// Returns a new UVec that only includes the values from the specified range, or an error
+    /// describing why the range is not contained within the `UVec`.
+    ///
+    /// ```
+    /// # use uvector::{RangeError, UVec};
+    /// let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
+    /// assert_eq!(uv.try_range(2..5).unwrap().len(), 3);
+    /// assert_eq!(
+    ///     uv.try_range(2..7).unwrap_err(),
+    ///     RangeError::EndOutOfRange { index: 7, len: 6 }
+    /// );
+    /// ```
+    pub fn try_range<R: RangeBounds<usize>>(&self, range: R) -> Result<Self, RangeError> {
+        let r = range::bounds(range, self.len())?;
+        let (r1, r2) = split_range(self.s.0.len(), r.start, r.end);
+        Ok(Self::new((&self.s.0[r1], &self.s.1[r2])))
+    }
+    /// Returns a new UVec that only includes the values from the specified range, or `None` if
+    /// the range is not contained within the `UVec`.
+    pub fn get_range<R: RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+        self.try_range(range).ok()
+    }
+    /// Returns a reference to the element at the given index, or `None` if the index is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
         let len1 = self.s.0.len();
-        let start1 = if start < len1 { start } else { len1 };
-        let end1 = if end < len1 { end } else { len1 };
-        let start2 = if start < len1 { 0 } else { start - len1 };
-        let end2 = if end < len1 { 0 } else { end - len1 };
-        Self::new((&self.s.0[start1..end1], &self.s.1[start2..end2]))
+        if index < len1 {
+            Some(&self.s.0[index])
+        } else {
+            self.s.1.get(index - len1)
+        }
+    }
+    /// Returns the first element of the vector, or `None` if it is empty.
+    pub fn first(&self) -> Option<&'a T> {
+        self.s.0.first().or_else(|| self.s.1.first())
+    }
+    /// Returns the last element of the vector, or `None` if it is empty.
+    pub fn last(&self) -> Option<&'a T> {
+        self.s.1.last().or_else(|| self.s.0.last())
+    }
+    /// Divides the vector into two at an index. The first will contain all indices from
+    /// `[0, mid)` and the second will contain all indices from `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
+    /// let (left, right) = uv.split_at(4);
+    /// assert_eq!(left.len(), 4);
+    /// assert_eq!(right[0], 5);
+    /// ```
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        let len = self.len();
+        assert!(mid <= len, "mid {} is out of range for length {}", mid, len);
+        (self.range(0..mid), self.range(mid..len))
+    }
+    /// Returns the first element and the rest of the vector, or `None` if it is empty.
+    pub fn split_first(&self) -> Option<(&'a T, Self)> {
+        let len = self.len();
+        self.first().map(|first| (first, self.range(1..len)))
+    }
+    /// Returns the last element and the rest of the vector, or `None` if it is empty.
+    pub fn split_last(&self) -> Option<(&'a T, Self)> {
+        let len = self.len();
+        self.last().map(|last| (last, self.range(0..len - 1)))
+    }
+    /// Returns `true` if the vector contains an element with the given value.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.s.0.contains(x) || self.s.1.contains(x)
     }
+    /// Returns `true` if `needle` is a prefix of the vector. The `needle` may be a slice, a
+    /// `VecDeque` or another `UVec`.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
+    /// assert!(uv.starts_with(&[1, 2, 3, 4]));
+    /// assert!(uv.starts_with(&UVec::new((&[1], &[2]))));
+    /// assert!(!uv.starts_with(&[2, 3]));
+    /// ```
+    pub fn starts_with<S>(&self, needle: &S) -> bool
+    where
+        S: AsSegments<T> + ?Sized,
+        T: PartialEq,
+    {
+        let needle = needle.as_uvec();
+        let n = needle.len();
+        n <= self.len() && self.range(0..n) == needle
+    }
+    /// Returns `true` if `needle` is a suffix of the vector. The `needle` may be a slice, a
+    /// `VecDeque` or another `UVec`.
+    pub fn ends_with<S>(&self, needle: &S) -> bool
+    where
+        S: AsSegments<T> + ?Sized,
+        T: PartialEq,
+    {
+        let needle = needle.as_uvec();
+        let (n, len) = (needle.len(), self.len());
+        n <= len && self.range(len - n..len) == needle
+    }
+    /// Searches for an element that satisfies a predicate, returning its index.
+    pub fn position<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&'a T) -> bool,
+    {
+        self.iter().position(pred)
+    }
+    /// Searches for an element that satisfies a predicate starting from the end, returning its
+    /// index.
+    pub fn rposition<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&'a T) -> bool,
+    {
+        self.iter().rposition(pred)
+    }
+}
+
+/// Splits the logical range `start..end` into ranges for the first and the second slice, given the
+/// length of the first slice.
+fn split_range(len1: usize, start: usize, end: usize) -> (Range<usize>, Range<usize>) {
+    let start1 = if start < len1 { start } else { len1 };
+    let end1 = if end < len1 { end } else { len1 };
+    let start2 = start.saturating_sub(len1);
+    let end2 = end.saturating_sub(len1);
+    (start1..end1, start2..end2)
 }
 
 impl<'a, T> Clone for UVec<'a, T> {
     fn clone(&self) -> Self {
-        UVec { s: self.s }
+        *self
     }
 }
 
+impl<'a, T> Copy for UVec<'a, T> {}
+
 impl<'a, T> Index<usize> for UVec<'a, T> {
     type Output = T;
     fn index(&self, index: usize) -> &T {
@@ -140,36 +492,98 @@ impl<'a, T> Index<usize> for UVec<'a, T> {
 }
 
 /// An iterator over the elements of a `UVec`
+///
+/// The iterator goes over the first slice and then over the second one, so the methods like
+/// `fold`, `nth` or `rev` run directly on the underlying slice iterators.
+#[derive(Debug)]
 pub struct Iter<'a, T: 'a> {
-    pos: usize,
-    s: (&'a [T], &'a [T]),
+    a: slice::Iter<'a, T>,
+    b: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            a: self.a.clone(),
+            b: self.b.clone(),
+        }
+    }
 }
 
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
     fn next(&mut self) -> Option<&'a T> {
-        let len1 = self.s.0.len();
-        let pos = self.pos;
-        if pos < len1 {
-            self.pos += 1;
-            Some(&self.s.0[pos])
+        match self.a.next() {
+            Some(v) => Some(v),
+            None => self.b.next(),
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+    fn count(self) -> usize {
+        self.len()
+    }
+    fn nth(&mut self, n: usize) -> Option<&'a T> {
+        let len1 = self.a.len();
+        if n < len1 {
+            self.a.nth(n)
+        } else {
+            self.a = [].iter();
+            self.b.nth(n - len1)
+        }
+    }
+    fn last(mut self) -> Option<&'a T> {
+        self.next_back()
+    }
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &'a T) -> B,
+    {
+        let acc = self.a.fold(init, &mut f);
+        self.b.fold(acc, f)
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        match self.b.next_back() {
+            Some(v) => Some(v),
+            None => self.a.next_back(),
+        }
+    }
+    fn nth_back(&mut self, n: usize) -> Option<&'a T> {
+        let len2 = self.b.len();
+        if n < len2 {
+            self.b.nth_back(n)
         } else {
-            let len2 = self.s.1.len();
-            if pos < len1 + len2 {
-                self.pos += 1;
-                Some(&self.s.1[pos - len1])
-            } else {
-                None
-            }
+            self.b = [].iter();
+            self.a.nth_back(n - len2)
         }
     }
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &'a T) -> B,
+    {
+        let acc = self.b.rfold(init, &mut f);
+        self.a.rfold(acc, f)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.a.len() + self.b.len()
+    }
 }
 
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
 impl<'a, T> IntoIterator for UVec<'a, T> {
     type Item = &'a T;
     type IntoIter = Iter<'a, T>;
     fn into_iter(self) -> Iter<'a, T> {
-        Iter { pos: 0, s: self.s }
+        self.iter()
     }
 }
 
@@ -184,6 +598,7 @@ impl<'a, T> IntoIterator for &'a UVec<'a, T> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::vec::Vec;
 
     #[test]
     fn index() {
@@ -210,25 +625,133 @@ mod test {
     #[test]
     fn subrange() {
         let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
-        let uv1 = uv.range(1, 5);
+        let uv1 = uv.range(1..5);
         assert_eq!(uv1.len(), 4);
         assert_eq!(uv1[0], 2);
         assert_eq!(uv1[3], 5);
-        let uv2 = uv.range(0, 2);
+        let uv2 = uv.range(0..2);
         assert_eq!(uv2.len(), 2);
         assert_eq!(uv2[1], 2);
-        let uv3 = uv.range(3, 4);
+        let uv3 = uv.range(3..4);
         assert_eq!(uv3.len(), 1);
         assert_eq!(uv3[0], 4);
-        let uv4 = uv.range(4, 4);
+        let uv4 = uv.range(4..4);
         assert_eq!(uv4.len(), 0);
+        assert_eq!(uv.range(..).len(), 6);
+        assert_eq!(uv.range(2..=3)[1], 4);
+        assert_eq!(uv.range(..=1).len(), 2);
+        assert_eq!(uv.range(6..).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 7 out of range for slice of length 6")]
+    fn subrange_end_outofrange() {
+        let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
+        uv.range(2..7);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 5 but ends at 4")]
+    fn subrange_start_after_end() {
+        let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
+        let (start, end) = (5, 4);
+        uv.range(start..end);
     }
 
     #[test]
+    #[should_panic(expected = "range start index 4 out of range for slice of length 3")]
+    fn subrange_start_outofrange() {
+        let uv = UVec::new((&[1, 2, 3], &[]));
+        uv.range(4..);
+    }
+
+    #[test]
+    fn get() {
+        let uv = UVec::new((&[1, 2], &[3]));
+        assert_eq!(uv.get(1), Some(&2));
+        assert_eq!(uv.get(2), Some(&3));
+        assert_eq!(uv.get(3), None);
+        assert_eq!(uv.first(), Some(&1));
+        assert_eq!(uv.last(), Some(&3));
+        let uv = UVec::new((&[1, 2], &[]));
+        assert_eq!(uv.last(), Some(&2));
+        let uv = UVec::new((&[], &[3]));
+        assert_eq!(uv.first(), Some(&3));
+        let empty: UVec<i32> = UVec::empty();
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.last(), None);
+        assert_eq!(uv.get_range(0..1).map(|r| r.len()), Some(1));
+        assert!(uv.get_range(0..4).is_none());
+        assert!(uv.get_range(1..).is_some());
+        assert!(uv.get_range(2..).is_none());
+    }
+
+    #[test]
+    fn split() {
+        let uv = UVec::new((&[1, 2, 3], &[4, 5]));
+        for mid in 0..6 {
+            let (l, r) = uv.split_at(mid);
+            assert_eq!(l.len(), mid);
+            assert_eq!(r.len(), 5 - mid);
+            assert_eq!(l.iter().chain(r.iter()).sum::<i32>(), 15);
+        }
+        let (first, rest) = uv.split_first().unwrap();
+        assert_eq!((*first, rest.len(), rest[0]), (1, 4, 2));
+        let (last, rest) = uv.split_last().unwrap();
+        assert_eq!((*last, rest.len(), rest[3]), (5, 4, 4));
+        assert!(UVec::<i32>::empty().split_first().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn contiguous() {
+        let uv = UVec::new((&[1, 2, 3], &[4, 5]));
+        let mut buf = [0; 5];
+        uv.copy_to_slice(&mut buf);
+        assert_eq!(buf, [1, 2, 3, 4, 5]);
+        uv.range(2..4).copy_to_slice(&mut buf[..2]);
+        assert_eq!(buf, [3, 4, 3, 4, 5]);
+        assert_eq!(uv.to_vec(), [1, 2, 3, 4, 5]);
+        assert_eq!(uv.as_single_slice(), None);
+        assert_eq!(uv.range(..3).as_single_slice(), Some(&[1, 2, 3][..]));
+        assert_eq!(UVec::<i32>::empty().as_single_slice(), Some(&[][..]));
+        match uv.range(3..).to_contiguous() {
+            Cow::Borrowed(s) => assert_eq!(s, [4, 5]),
+            Cow::Owned(_) => panic!("unexpected allocation"),
+        }
+        assert_eq!(&*uv.range(1..4).to_contiguous(), [2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "destination and source slices have different lengths")]
+    fn copy_to_slice_len() {
+        let uv = UVec::new((&[1, 2, 3], &[4, 5]));
+        uv.copy_to_slice(&mut [0; 4]);
+    }
+
+    #[test]
+    fn search() {
+        let uv = UVec::new((&[1, 2, 3], &[4, 3]));
+        assert!(uv.contains(&4));
+        assert!(!uv.contains(&5));
+        assert_eq!(uv.position(|&x| x == 3), Some(2));
+        assert_eq!(uv.rposition(|&x| x == 3), Some(4));
+        assert_eq!(uv.position(|&x| x > 3), Some(3));
+        assert_eq!(uv.rposition(|&x| x > 5), None);
+        assert!(uv.starts_with(&[]));
+        assert!(uv.starts_with(&[1, 2, 3, 4, 3]));
+        assert!(!uv.starts_with(&[1, 2, 3, 4, 3, 2]));
+        assert!(uv.ends_with(&[3, 4, 3]));
+        assert!(uv.ends_with(&uv.range(2..5)));
+        assert!(!uv.ends_with(&[4]));
+    }
+
+    #[test]
+    #[allow(clippy::map_clone)]
     fn iter() {
         let uv = UVec::new((&[1i32, 2, 3], &[4, 5, 6]));
         assert_eq!(
-            uv.range(2, 4).iter().map(|x| *x).collect::<Vec<i32>>(),
+            uv.range(2..4).iter().map(|x| *x).collect::<Vec<i32>>(),
             vec![3, 4]
         );
         let mut sum = 0i32;
@@ -237,16 +760,44 @@ mod test {
         }
         assert_eq!(sum, 21);
         let mut sum2 = 0;
-        for i in uv.range(1, 5) {
+        for i in uv.range(1..5) {
             sum2 += i
         }
         assert_eq!(sum2, 14);
     }
 
     #[test]
+    fn iter_double_ended() {
+        let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
+        assert_eq!(
+            uv.iter().rev().cloned().collect::<Vec<i32>>(),
+            [6, 5, 4, 3, 2, 1]
+        );
+        let mut it = uv.iter();
+        assert_eq!(it.len(), 6);
+        assert_eq!(it.next(), Some(&1));
+        assert_eq!(it.next_back(), Some(&6));
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.nth_back(1), Some(&4));
+        assert_eq!(it.nth(1), Some(&3));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+        let mut it = uv.iter();
+        assert_eq!(it.nth(4), Some(&5));
+        assert_eq!(it.size_hint(), (1, Some(1)));
+        assert_eq!(uv.iter().skip(2).fold(0, |acc, x| acc * 10 + x), 3456);
+        assert_eq!(uv.iter().rfold(0, |acc, x| acc * 10 + x), 654321);
+        assert_eq!(uv.iter().last(), Some(&6));
+        assert_eq!(UVec::new((&[1], &[])).iter().last(), Some(&1));
+    }
+
+    #[test]
+    #[allow(clippy::clone_on_copy)]
     fn clone() {
         let uv = UVec::new((&[1, 2], &[3, 4]));
         let cv = uv.clone();
         assert_eq!(cv.len(), 4);
+        let copy = uv;
+        assert_eq!(copy, uv);
     }
 }
@@ -0,0 +1,270 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use core::ops::RangeBounds;
+use core::ptr;
+use core::slice;
+use std::io;
+
+use libc::c_void;
+
+use super::UVec;
+
+/// Byte ring buffer which memory pages are mapped twice back-to-back, so its content is always
+/// available as a single contiguous slice, even if it wraps around the end of the buffer.
+///
+/// The capacity is rounded up to a multiple of the page size. The buffer is only available on
+/// Linux, it is backed by a `memfd_create` file mapped with `mmap`.
+///
+/// # Examples
+///
+/// ```
+/// use uvector::MirroredRing;
+///
+/// let mut ring = MirroredRing::new(4096).unwrap();
+/// let cap = ring.capacity();
+/// ring.write(&vec![0; cap - 2]);
+/// ring.consume(cap - 2);
+/// ring.write(b"wrapped");
+/// assert_eq!(ring.as_slice(), b"wrapped");
+/// assert_eq!(ring.view().range(..4), b"wrap"[..]);
+/// ```
+#[derive(Debug)]
+pub struct MirroredRing {
+    ptr: *mut u8,
+    cap: usize,
+    head: usize,
+    len: usize,
+}
+
+// SAFETY: the buffer exclusively owns its mapping, shared access only allows reading it
+unsafe impl Send for MirroredRing {}
+unsafe impl Sync for MirroredRing {}
+
+impl MirroredRing {
+    /// Constructs a new empty `MirroredRing` that can hold at least `min_capacity` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the OS error if creating or mapping the memory file fails.
+    pub fn new(min_capacity: usize) -> io::Result<Self> {
+        // SAFETY: sysconf has no preconditions
+        let page = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        let cap = match min_capacity.max(1).checked_next_multiple_of(page) {
+            Some(cap) if cap <= isize::MAX as usize / 2 => cap,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "capacity overflow",
+                ))
+            }
+        };
+        // SAFETY: the name is a NUL-terminated string
+        let fd =
+            unsafe { libc::memfd_create(b"uvector\0".as_ptr() as *const _, libc::MFD_CLOEXEC) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let res = Self::map(fd, cap);
+        // SAFETY: `fd` is a file descriptor we own, the mappings keep the file alive
+        unsafe { libc::close(fd) };
+        let ptr = res?;
+        Ok(MirroredRing {
+            ptr,
+            cap,
+            head: 0,
+            len: 0,
+        })
+    }
+    /// Maps the file `fd` of size `cap` twice into a contiguous region of `2 * cap` bytes.
+    fn map(fd: libc::c_int, cap: usize) -> io::Result<*mut u8> {
+        // SAFETY: FFI calls with valid arguments, the fixed mappings replace pages of the region
+        // reserved by the first `mmap`, which is unmapped on failure
+        unsafe {
+            if libc::ftruncate(fd, cap as libc::off_t) < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let base = libc::mmap(
+                ptr::null_mut(),
+                2 * cap,
+                libc::PROT_NONE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            );
+            if base == libc::MAP_FAILED {
+                return Err(io::Error::last_os_error());
+            }
+            for half in 0..2 {
+                let addr = libc::mmap(
+                    (base as *mut u8).add(half * cap) as *mut c_void,
+                    cap,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    libc::MAP_SHARED | libc::MAP_FIXED,
+                    fd,
+                    0,
+                );
+                if addr == libc::MAP_FAILED {
+                    let err = io::Error::last_os_error();
+                    libc::munmap(base, 2 * cap);
+                    return Err(err);
+                }
+            }
+            Ok(base as *mut u8)
+        }
+    }
+    /// Returns the maximum number of bytes the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+    /// Returns the number of bytes in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns `true` if the buffer contains no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Returns `true` if the buffer contains `capacity` bytes.
+    pub fn is_full(&self) -> bool {
+        self.len == self.cap
+    }
+    /// Removes all bytes from the buffer.
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+    /// Appends as many bytes from `data` as fit into the buffer, returns the number of bytes
+    /// written.
+    pub fn write(&mut self, data: &[u8]) -> usize {
+        let spare = self.spare_mut();
+        let n = spare.len().min(data.len());
+        spare[..n].copy_from_slice(&data[..n]);
+        self.commit(n);
+        n
+    }
+    /// Returns the free space after the content of the buffer as a single slice. Bytes written
+    /// into it are appended to the content by `commit`.
+    pub fn spare_mut(&mut self) -> &mut [u8] {
+        // SAFETY: `head + len + (cap - len) <= 2 * cap`, the region is within the mapping
+        unsafe {
+            slice::from_raw_parts_mut(self.ptr.add(self.head + self.len), self.cap - self.len)
+        }
+    }
+    /// Appends the first `n` bytes of `spare_mut` to the content of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the free space in the buffer.
+    pub fn commit(&mut self, n: usize) {
+        assert!(
+            n <= self.cap - self.len,
+            "commit of {} bytes exceeds free space of {}",
+            n,
+            self.cap - self.len
+        );
+        self.len += n;
+    }
+    /// Removes `n` bytes from the front of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the length of the buffer.
+    pub fn consume(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "consume of {} bytes exceeds length {}",
+            n,
+            self.len
+        );
+        self.head = (self.head + n) % self.cap;
+        self.len -= n;
+    }
+    /// Returns the content of the buffer as a single slice.
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: `head < cap` and `len <= cap`, the region is within the mapping
+        unsafe { slice::from_raw_parts(self.ptr.add(self.head), self.len) }
+    }
+    /// Returns the content of the buffer as a single mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: same as `as_slice`, `&mut self` guarantees exclusive access
+        unsafe { slice::from_raw_parts_mut(self.ptr.add(self.head), self.len) }
+    }
+    /// Returns a read-only view of the buffer content. The view always consists of a single
+    /// slice.
+    pub fn view(&self) -> UVec<'_, u8> {
+        UVec::new((self.as_slice(), &[]))
+    }
+    /// Returns a read-only view of the specified range of the buffer content.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified range is not contained within the buffer.
+    pub fn view_range<R: RangeBounds<usize>>(&self, range: R) -> UVec<'_, u8> {
+        self.view().range(range)
+    }
+}
+
+impl Drop for MirroredRing {
+    fn drop(&mut self) {
+        // SAFETY: the region was mapped in `new` and is not referenced after drop
+        unsafe { libc::munmap(self.ptr as *mut c_void, 2 * self.cap) };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn capacity() {
+        let ring = MirroredRing::new(1).unwrap();
+        assert!(ring.capacity() >= 1);
+        assert!(ring.is_empty());
+        let page = ring.capacity();
+        assert_eq!(MirroredRing::new(page + 1).unwrap().capacity(), 2 * page);
+        assert!(MirroredRing::new(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn wrap_around() {
+        let mut ring = MirroredRing::new(1).unwrap();
+        let cap = ring.capacity();
+        let data: Vec<u8> = (0..cap).map(|i| i as u8).collect();
+        assert_eq!(ring.write(&data), cap);
+        assert!(ring.is_full());
+        assert_eq!(ring.write(b"x"), 0);
+        ring.consume(cap - 3);
+        assert_eq!(ring.write(b"abcdef"), 6);
+        assert_eq!(ring.len(), 9);
+        assert_eq!(&ring.as_slice()[..3], &data[cap - 3..]);
+        assert_eq!(&ring.as_slice()[3..], b"abcdef");
+        assert_eq!(ring.view_range(3..5), b"ab"[..]);
+        ring.as_mut_slice()[3] = b'A';
+        ring.consume(3);
+        assert_eq!(ring.as_slice(), b"Abcdef");
+        ring.clear();
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    fn spare_commit() {
+        let mut ring = MirroredRing::new(1).unwrap();
+        let cap = ring.capacity();
+        ring.commit(cap - 1);
+        ring.consume(cap - 1);
+        let spare = ring.spare_mut();
+        assert_eq!(spare.len(), cap);
+        spare[..4].copy_from_slice(b"ring");
+        ring.commit(4);
+        assert_eq!(ring.view(), b"ring"[..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "consume of 1 bytes exceeds length 0")]
+    fn consume_too_much() {
+        MirroredRing::new(1).unwrap().consume(1);
+    }
+}
@@ -0,0 +1,707 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::mem;
+use core::ops::{Index, IndexMut, RangeBounds};
+use core::slice;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::{range, split_range, AsSegments, Iter, RangeError, UVec};
+use super::{Chunks, ChunksExact, RChunks, Windows};
+
+/// Mutable array type allowing access two slices as a single continuous vector.
+///
+/// This is a mutable counterpart of `UVec`. It can be used for example to modify the content of a
+/// `VecDeque` in place:
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use uvector::UVecMut;
+///
+/// let mut vd: VecDeque<i32> = VecDeque::new();
+/// for i in 1..4 {
+///     vd.push_back(i);
+///     vd.push_front(-i);
+/// }
+/// {
+///     let mut uv = UVecMut::new(vd.as_mut_slices());
+///     uv.reverse();
+///     uv[0] = 42;
+/// }
+/// assert_eq!(vd, [42, 2, 1, -1, -2, -3]);
+/// ```
+#[derive(Debug)]
+pub struct UVecMut<'a, T: 'a> {
+    s: (&'a mut [T], &'a mut [T]),
+}
+
+impl<'a, T> UVecMut<'a, T> {
+    /// Constructs a new `UVecMut<T>` from a tupple of two mutable slices
+    pub fn new(s: (&'a mut [T], &'a mut [T])) -> Self {
+        UVecMut { s }
+    }
+    /// Constructs a new empty `UVecMut<T>`
+    pub fn empty() -> Self {
+        UVecMut {
+            s: (&mut [], &mut []),
+        }
+    }
+    /// Consumes the vector, returning the two mutable slices it consists of.
+    pub fn into_slices(self) -> (&'a mut [T], &'a mut [T]) {
+        self.s
+    }
+    /// Returns a read-only `UVec` view of the vector.
+    pub fn as_uvec(&self) -> UVec<'_, T> {
+        UVec::new((&*self.s.0, &*self.s.1))
+    }
+    /// Returns the length of the vector. The length is determined as the sum of lengths of all the
+    /// components.
+    pub fn len(&self) -> usize {
+        self.s.0.len() + self.s.1.len()
+    }
+    /// Returns `true` if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.s.0.is_empty() && self.s.1.is_empty()
+    }
+    /// Returns a reference to the element at the given index, or `None` if the index is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_uvec().get(index)
+    }
+    /// Returns a mutable reference to the element at the given index, or `None` if the index is
+    /// out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let len1 = self.s.0.len();
+        if index < len1 {
+            Some(&mut self.s.0[index])
+        } else {
+            self.s.1.get_mut(index - len1)
+        }
+    }
+    /// Returns the first element of the vector, or `None` if it is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.as_uvec().first()
+    }
+    /// Returns a mutable reference to the first element of the vector, or `None` if it is empty.
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+    /// Returns the last element of the vector, or `None` if it is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.as_uvec().last()
+    }
+    /// Returns a mutable reference to the last element of the vector, or `None` if it is empty.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        match self.s.1.last_mut() {
+            Some(v) => Some(v),
+            None => self.s.0.last_mut(),
+        }
+    }
+    /// Returns `true` if the vector contains an element with the given value.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_uvec().contains(x)
+    }
+    /// Returns `true` if `needle` is a prefix of the vector.
+    pub fn starts_with<S>(&self, needle: &S) -> bool
+    where
+        S: AsSegments<T> + ?Sized,
+        T: PartialEq,
+    {
+        self.as_uvec().starts_with(needle)
+    }
+    /// Returns `true` if `needle` is a suffix of the vector.
+    pub fn ends_with<S>(&self, needle: &S) -> bool
+    where
+        S: AsSegments<T> + ?Sized,
+        T: PartialEq,
+    {
+        self.as_uvec().ends_with(needle)
+    }
+    /// Searches for an element that satisfies a predicate, returning its index.
+    pub fn position<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().position(pred)
+    }
+    /// Searches for an element that satisfies a predicate starting from the end, returning its
+    /// index.
+    pub fn rposition<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter().rposition(pred)
+    }
+    /// Binary searches a sorted vector for the given element, see `UVec::binary_search`.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.as_uvec().binary_search(x)
+    }
+    /// Binary searches a sorted vector with a comparator function, see `UVec::binary_search_by`.
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        self.as_uvec().binary_search_by(f)
+    }
+    /// Binary searches a sorted vector with a key extraction function, see
+    /// `UVec::binary_search_by_key`.
+    pub fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.as_uvec().binary_search_by_key(b, f)
+    }
+    /// Returns the index of the partition point according to the given predicate, see
+    /// `UVec::partition_point`.
+    pub fn partition_point<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.as_uvec().partition_point(pred)
+    }
+    /// Returns iterator over `UVecMut`
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            a: self.s.0.iter(),
+            b: self.s.1.iter(),
+        }
+    }
+    /// Returns iterator that allows modifying each value
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            a: self.s.0.iter_mut(),
+            b: self.s.1.iter_mut(),
+        }
+    }
+    /// Returns the content of the vector as a single slice if it is not split between two
+    /// slices, see `UVec::as_single_slice`.
+    pub fn as_single_slice(&self) -> Option<&[T]> {
+        self.as_uvec().as_single_slice()
+    }
+    /// Returns the content of the vector as a single mutable slice if it is not split between
+    /// two slices.
+    pub fn as_single_slice_mut(&mut self) -> Option<&mut [T]> {
+        if self.s.1.is_empty() {
+            Some(&mut *self.s.0)
+        } else if self.s.0.is_empty() {
+            Some(&mut *self.s.1)
+        } else {
+            None
+        }
+    }
+    /// Copies all elements of the vector into `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` has a different length than the vector.
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        self.as_uvec().copy_to_slice(dst)
+    }
+    /// Copies all elements from `src` into the vector, `src` may be split differently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` has a different length than the vector.
+    pub fn copy_from_slice<S>(&mut self, src: &S)
+    where
+        T: Copy,
+        S: AsSegments<T> + ?Sized,
+    {
+        let src = src.as_uvec();
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+        let (s1, s2) = src.split_at(self.s.0.len());
+        s1.copy_to_slice(self.s.0);
+        s2.copy_to_slice(self.s.1);
+    }
+    /// Copies the vector into a new `Vec`.
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.as_uvec().to_vec()
+    }
+    /// Returns the content of the vector as a single slice, only allocating if it is split
+    /// between two slices, see `UVec::to_contiguous`.
+    #[cfg(feature = "alloc")]
+    pub fn to_contiguous(&self) -> Cow<'_, [T]>
+    where
+        T: Clone,
+    {
+        self.as_uvec().to_contiguous()
+    }
+    /// Returns an iterator over all contiguous windows of length `size`, see `UVec::windows`.
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        self.as_uvec().windows(size)
+    }
+    /// Returns an iterator over `size` elements at a time, see `UVec::chunks`.
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        self.as_uvec().chunks(size)
+    }
+    /// Returns an iterator over `size` elements at a time, see `UVec::chunks_exact`.
+    pub fn chunks_exact(&self, size: usize) -> ChunksExact<'_, T> {
+        self.as_uvec().chunks_exact(size)
+    }
+    /// Returns an iterator over `size` elements at a time starting at the end, see
+    /// `UVec::rchunks`.
+    pub fn rchunks(&self, size: usize) -> RChunks<'_, T> {
+        self.as_uvec().rchunks(size)
+    }
+    /// Returns a read-only `UVec` that only includes the values from the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified range is not contained within the `UVecMut`
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> UVec<'_, T> {
+        self.as_uvec().range(range)
+    }
+    /// Returns a read-only `UVec` that only includes the values from the specified range, or an
+    /// error describing why the range is not contained within the `UVecMut`.
+    pub fn try_range<R: RangeBounds<usize>>(&self, range: R) -> Result<UVec<'_, T>, RangeError> {
+        self.as_uvec().try_range(range)
+    }
+    /// Returns a new `UVecMut` that reborrows the values from the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified range is not contained within the `UVecMut`
+    ///
+    /// ```
+    /// # use uvector::UVecMut;
+    /// let (a, b) = (&mut [1, 2, 3], &mut [4, 5, 6]);
+    /// let mut uv = UVecMut::new((a, b));
+    /// uv.range_mut(2..4).fill(0);
+    /// assert_eq!(uv.as_uvec().iter().cloned().collect::<Vec<_>>(), [1, 2, 0, 0, 5, 6]);
+    /// ```
+    pub fn range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> UVecMut<'_, T> {
+        match self.try_range_mut(range) {
+            Ok(uv) => uv,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    /// Returns a new `UVecMut` that reborrows the values from the specified range, or an error
+    /// describing why the range is not contained within the `UVecMut`.
+    pub fn try_range_mut<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> Result<UVecMut<'_, T>, RangeError> {
+        let r = range::bounds(range, self.len())?;
+        let (r1, r2) = split_range(self.s.0.len(), r.start, r.end);
+        Ok(UVecMut::new((&mut self.s.0[r1], &mut self.s.1[r2])))
+    }
+    /// Returns a read-only `UVec` that only includes the values from the specified range, or
+    /// `None` if the range is not contained within the `UVecMut`.
+    pub fn get_range<R: RangeBounds<usize>>(&self, range: R) -> Option<UVec<'_, T>> {
+        self.as_uvec().get_range(range)
+    }
+    /// Divides the vector into two read-only `UVec`s at an index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at(&self, mid: usize) -> (UVec<'_, T>, UVec<'_, T>) {
+        self.as_uvec().split_at(mid)
+    }
+    /// Divides the vector into two mutable `UVecMut`s at an index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at_mut(&mut self, mid: usize) -> (UVecMut<'_, T>, UVecMut<'_, T>) {
+        let len = self.len();
+        assert!(mid <= len, "mid {} is out of range for length {}", mid, len);
+        let len1 = self.s.0.len();
+        if mid <= len1 {
+            let (lo, hi) = self.s.0.split_at_mut(mid);
+            (
+                UVecMut::new((lo, &mut [])),
+                UVecMut::new((hi, &mut *self.s.1)),
+            )
+        } else {
+            let (lo, hi) = self.s.1.split_at_mut(mid - len1);
+            (
+                UVecMut::new((&mut *self.s.0, lo)),
+                UVecMut::new((&mut [], hi)),
+            )
+        }
+    }
+    /// Returns the first element and the rest of the vector, or `None` if it is empty.
+    pub fn split_first(&self) -> Option<(&T, UVec<'_, T>)> {
+        self.as_uvec().split_first()
+    }
+    /// Returns the last element and the rest of the vector, or `None` if it is empty.
+    pub fn split_last(&self) -> Option<(&T, UVec<'_, T>)> {
+        self.as_uvec().split_last()
+    }
+    /// Returns a mutable reference to the first element and the rest of the vector, or `None` if
+    /// it is empty.
+    pub fn split_first_mut(&mut self) -> Option<(&mut T, UVecMut<'_, T>)> {
+        if self.is_empty() {
+            return None;
+        }
+        let (first, rest) = self.split_at_mut(1);
+        let (a, b) = first.into_slices();
+        a.iter_mut().chain(b).next().map(|first| (first, rest))
+    }
+    /// Returns a mutable reference to the last element and the rest of the vector, or `None` if
+    /// it is empty.
+    pub fn split_last_mut(&mut self) -> Option<(&mut T, UVecMut<'_, T>)> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let (rest, last) = self.split_at_mut(len - 1);
+        let (a, b) = last.into_slices();
+        a.iter_mut().chain(b).next().map(|last| (last, rest))
+    }
+    /// Swaps two elements in the vector. The elements may belong to different slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let len1 = self.s.0.len();
+        match (a < len1, b < len1) {
+            (true, true) => self.s.0.swap(a, b),
+            (false, false) => self.s.1.swap(a - len1, b - len1),
+            (true, false) => mem::swap(&mut self.s.0[a], &mut self.s.1[b - len1]),
+            (false, true) => mem::swap(&mut self.s.0[b], &mut self.s.1[a - len1]),
+        }
+    }
+    /// Fills the vector with clones of `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.s.0.fill(value.clone());
+        self.s.1.fill(value);
+    }
+    /// Reverses the order of elements in the vector, in place.
+    pub fn reverse(&mut self) {
+        if self.s.1.is_empty() {
+            return self.s.0.reverse();
+        }
+        if self.s.0.is_empty() {
+            return self.s.1.reverse();
+        }
+        let len = self.len();
+        for i in 0..len / 2 {
+            self.swap(i, len - 1 - i);
+        }
+    }
+    /// Rotates the vector in-place such that the first `mid` elements move to the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the vector.
+    pub fn rotate_left(&mut self, mid: usize) {
+        let len = self.len();
+        assert!(mid <= len, "mid {} is out of range for length {}", mid, len);
+        if self.s.1.is_empty() {
+            return self.s.0.rotate_left(mid);
+        }
+        if self.s.0.is_empty() {
+            return self.s.1.rotate_left(mid);
+        }
+        self.range_mut(0..mid).reverse();
+        self.range_mut(mid..len).reverse();
+        self.reverse();
+    }
+    /// Rotates the vector in-place such that the last `k` elements move to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the length of the vector.
+    pub fn rotate_right(&mut self, k: usize) {
+        let len = self.len();
+        assert!(k <= len, "k {} is out of range for length {}", k, len);
+        self.rotate_left(len - k);
+    }
+}
+
+impl<'a, T> Index<usize> for UVecMut<'a, T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        let len = self.s.0.len();
+        if index < len {
+            &self.s.0[index]
+        } else {
+            &self.s.1[index - len]
+        }
+    }
+}
+
+impl<'a, T> IndexMut<usize> for UVecMut<'a, T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.s.0.len();
+        if index < len {
+            &mut self.s.0[index]
+        } else {
+            &mut self.s.1[index - len]
+        }
+    }
+}
+
+/// A mutable iterator over the elements of a `UVecMut`
+#[derive(Debug)]
+pub struct IterMut<'a, T: 'a> {
+    a: slice::IterMut<'a, T>,
+    b: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<&'a mut T> {
+        match self.a.next() {
+            Some(v) => Some(v),
+            None => self.b.next(),
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+    fn count(self) -> usize {
+        self.len()
+    }
+    fn nth(&mut self, n: usize) -> Option<&'a mut T> {
+        let len1 = self.a.len();
+        if n < len1 {
+            self.a.nth(n)
+        } else {
+            self.a = [].iter_mut();
+            self.b.nth(n - len1)
+        }
+    }
+    fn last(mut self) -> Option<&'a mut T> {
+        self.next_back()
+    }
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &'a mut T) -> B,
+    {
+        let acc = self.a.fold(init, &mut f);
+        self.b.fold(acc, f)
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        match self.b.next_back() {
+            Some(v) => Some(v),
+            None => self.a.next_back(),
+        }
+    }
+    fn nth_back(&mut self, n: usize) -> Option<&'a mut T> {
+        let len2 = self.b.len();
+        if n < len2 {
+            self.b.nth_back(n)
+        } else {
+            self.b = [].iter_mut();
+            self.a.nth_back(n - len2)
+        }
+    }
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &'a mut T) -> B,
+    {
+        let acc = self.b.rfold(init, &mut f);
+        self.a.rfold(acc, f)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.a.len() + self.b.len()
+    }
+}
+
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
+impl<'a, T> IntoIterator for UVecMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> IterMut<'a, T> {
+        IterMut {
+            a: self.s.0.iter_mut(),
+            b: self.s.1.iter_mut(),
+        }
+    }
+}
+
+impl<'a, 'b, T> IntoIterator for &'a UVecMut<'b, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, 'b, T> IntoIterator for &'a mut UVecMut<'b, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::vec::Vec;
+
+    fn collect(uv: &UVecMut<i32>) -> Vec<i32> {
+        uv.iter().cloned().collect()
+    }
+
+    #[test]
+    fn index_mut() {
+        let (one, two) = (&mut [1, 2, 3], &mut [4, 5]);
+        let mut uv = UVecMut::new((one, two));
+        assert_eq!(uv.len(), 5);
+        uv[0] = 10;
+        uv[3] = 40;
+        assert_eq!(uv[0], 10);
+        assert_eq!(uv[3], 40);
+        for i in &mut uv {
+            *i += 1;
+        }
+        assert_eq!(collect(&uv), [11, 3, 4, 41, 6]);
+        assert_eq!(uv.range(2..4).len(), 2);
+        for i in uv.iter_mut().rev().skip(1).step_by(2) {
+            *i = 0;
+        }
+        assert_eq!(collect(&uv), [11, 0, 4, 0, 6]);
+    }
+
+    #[test]
+    fn slice_api() {
+        let (one, two) = (&mut [1, 2, 3], &mut [4, 5]);
+        let mut uv = UVecMut::new((one, two));
+        *uv.first_mut().unwrap() = 0;
+        *uv.last_mut().unwrap() = 6;
+        *uv.get_mut(3).unwrap() += 10;
+        assert!(uv.get_mut(5).is_none());
+        assert_eq!(collect(&uv), [0, 2, 3, 14, 6]);
+        assert!(uv.starts_with(&[0, 2]));
+        assert!(uv.ends_with(&[14, 6]));
+        assert!(uv.contains(&14));
+        assert_eq!(uv.position(|&x| x > 10), Some(3));
+        for mid in 0..6 {
+            let (mut l, mut r) = uv.split_at_mut(mid);
+            assert_eq!(l.len() + r.len(), 5);
+            l.fill(1);
+            r.fill(2);
+            assert_eq!(uv.get(mid), if mid < 5 { Some(&2) } else { None });
+        }
+    }
+
+    #[test]
+    fn split_first_last() {
+        let (one, two) = (&mut [1, 2, 3], &mut [4, 5]);
+        let mut uv = UVecMut::new((one, two));
+        let (first, rest) = uv.split_first().unwrap();
+        assert_eq!((*first, rest.len()), (1, 4));
+        let (last, rest) = uv.split_last().unwrap();
+        assert_eq!((*last, rest.len()), (5, 4));
+        {
+            let (first, mut rest) = uv.split_first_mut().unwrap();
+            *first = 10;
+            let (last, rest) = rest.split_last_mut().unwrap();
+            *last = 50;
+            assert_eq!(rest.len(), 3);
+        }
+        assert_eq!(collect(&uv), [10, 2, 3, 4, 50]);
+        let mut tail = uv.range_mut(3..);
+        let (first, rest) = tail.split_first_mut().unwrap();
+        *first = 40;
+        assert_eq!(rest.len(), 1);
+        assert_eq!(collect(&uv), [10, 2, 3, 40, 50]);
+        assert_eq!(
+            uv.try_range(2..6).unwrap_err(),
+            RangeError::EndOutOfRange { index: 6, len: 5 }
+        );
+        assert_eq!(uv.try_range(1..3).unwrap(), [2, 3]);
+        let mut empty: UVecMut<i32> = UVecMut::empty();
+        assert!(empty.split_first().is_none());
+        assert!(empty.split_last_mut().is_none());
+        assert!(empty.split_first_mut().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn contiguous() {
+        let (one, two) = (&mut [1, 2, 3], &mut [4, 5]);
+        let mut uv = UVecMut::new((one, two));
+        uv.copy_from_slice(&[5, 4, 3, 2, 1]);
+        assert_eq!(uv.to_vec(), [5, 4, 3, 2, 1]);
+        assert!(uv.as_single_slice_mut().is_none());
+        uv.range_mut(3..).as_single_slice_mut().unwrap()[0] = 0;
+        assert_eq!(uv.to_vec(), [5, 4, 3, 0, 1]);
+        uv.copy_from_slice(&UVec::new((&[6], &[7, 8, 9, 10])));
+        assert_eq!(uv.to_vec(), [6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn swap() {
+        let (one, two) = (&mut [1, 2, 3], &mut [4, 5]);
+        let mut uv = UVecMut::new((one, two));
+        uv.swap(0, 1);
+        uv.swap(3, 4);
+        uv.swap(2, 4);
+        uv.swap(3, 0);
+        assert_eq!(collect(&uv), [5, 1, 4, 2, 3]);
+    }
+
+    #[test]
+    fn reverse() {
+        let (one, two) = (&mut [1, 2], &mut [3, 4, 5]);
+        let mut uv = UVecMut::new((one, two));
+        uv.reverse();
+        assert_eq!(collect(&uv), [5, 4, 3, 2, 1]);
+        uv.range_mut(1..3).reverse();
+        assert_eq!(collect(&uv), [5, 3, 4, 2, 1]);
+    }
+
+    #[test]
+    fn rotate() {
+        let (one, two) = (&mut [1, 2, 3], &mut [4, 5, 6, 7]);
+        let mut uv = UVecMut::new((one, two));
+        uv.rotate_left(2);
+        assert_eq!(collect(&uv), [3, 4, 5, 6, 7, 1, 2]);
+        uv.rotate_right(3);
+        assert_eq!(collect(&uv), [7, 1, 2, 3, 4, 5, 6]);
+        uv.rotate_left(7);
+        assert_eq!(collect(&uv), [7, 1, 2, 3, 4, 5, 6]);
+        uv.range_mut(1..7).rotate_left(1);
+        assert_eq!(collect(&uv), [7, 2, 3, 4, 5, 6, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotate_outofrange() {
+        let (one, two) = (&mut [1, 2], &mut [3]);
+        UVecMut::new((one, two)).rotate_left(4);
+    }
+}
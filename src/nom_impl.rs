@@ -0,0 +1,288 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+//! Input traits of `nom` 7 for `UVec<u8>`, so the parsers work across the point where the first
+//! slice ends.
+//!
+//! ```
+//! # extern crate nom;
+//! # extern crate uvector;
+//! use nom::bytes::complete::{tag, take_until};
+//! use nom::error::Error;
+//! use nom::number::complete::be_u32;
+//! use nom::sequence::tuple;
+//! use nom::IResult;
+//! use uvector::UVec;
+//!
+//! fn frame(i: UVec<u8>) -> IResult<UVec<u8>, (UVec<u8>, u32), Error<UVec<u8>>> {
+//!     let (i, (_, name, _, len)) = tuple((tag("NAME "), take_until("\n"), tag("\n"), be_u32))(i)?;
+//!     Ok((i, (name, len)))
+//! }
+//!
+//! let uv = UVec::new((b"NAME fr", b"ame\n\0\0\x01\x02rest"));
+//! let (rest, (name, len)) = frame(uv).unwrap();
+//! assert_eq!(name, b"frame"[..]);
+//! assert_eq!(len, 0x0102);
+//! assert_eq!(rest, b"rest"[..]);
+//! ```
+
+use core::iter::{Copied, Enumerate};
+use core::ops::{Range, RangeFrom, RangeFull, RangeTo};
+
+use nom::error::{ErrorKind, ParseError};
+use nom::{
+    Compare, CompareResult, Err, FindSubstring, FindToken, IResult, InputIter, InputLength,
+    InputTake, InputTakeAtPosition, Needed, Offset, Slice,
+};
+
+use super::{Iter, UVec};
+
+impl<'a> InputLength for UVec<'a, u8> {
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<'a> InputTake for UVec<'a, u8> {
+    fn take(&self, count: usize) -> Self {
+        self.range(..count)
+    }
+    /// Returns the suffix first, as `nom` expects.
+    fn take_split(&self, count: usize) -> (Self, Self) {
+        let (prefix, suffix) = self.split_at(count);
+        (suffix, prefix)
+    }
+}
+
+impl<'a> InputIter for UVec<'a, u8> {
+    type Item = u8;
+    type Iter = Enumerate<Self::IterElem>;
+    type IterElem = Copied<Iter<'a, u8>>;
+
+    fn iter_indices(&self) -> Self::Iter {
+        self.iter_elements().enumerate()
+    }
+    fn iter_elements(&self) -> Self::IterElem {
+        self.iter().copied()
+    }
+    fn position<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(u8) -> bool,
+    {
+        self.iter().position(|&b| predicate(b))
+    }
+    fn slice_index(&self, count: usize) -> Result<usize, Needed> {
+        if count <= self.len() {
+            Ok(count)
+        } else {
+            Err(Needed::new(count - self.len()))
+        }
+    }
+}
+
+impl<'a> InputTakeAtPosition for UVec<'a, u8> {
+    type Item = u8;
+
+    fn split_at_position<P, E: ParseError<Self>>(&self, predicate: P) -> IResult<Self, Self, E>
+    where
+        P: Fn(u8) -> bool,
+    {
+        match InputIter::position(self, predicate) {
+            Some(n) => Ok(self.take_split(n)),
+            None => Err(Err::Incomplete(Needed::new(1))),
+        }
+    }
+    fn split_at_position1<P, E: ParseError<Self>>(
+        &self,
+        predicate: P,
+        e: ErrorKind,
+    ) -> IResult<Self, Self, E>
+    where
+        P: Fn(u8) -> bool,
+    {
+        match InputIter::position(self, predicate) {
+            Some(0) => Err(Err::Error(E::from_error_kind(*self, e))),
+            Some(n) => Ok(self.take_split(n)),
+            None => Err(Err::Incomplete(Needed::new(1))),
+        }
+    }
+    fn split_at_position_complete<P, E: ParseError<Self>>(
+        &self,
+        predicate: P,
+    ) -> IResult<Self, Self, E>
+    where
+        P: Fn(u8) -> bool,
+    {
+        let n = InputIter::position(self, predicate).unwrap_or(self.len());
+        Ok(self.take_split(n))
+    }
+    fn split_at_position1_complete<P, E: ParseError<Self>>(
+        &self,
+        predicate: P,
+        e: ErrorKind,
+    ) -> IResult<Self, Self, E>
+    where
+        P: Fn(u8) -> bool,
+    {
+        match InputIter::position(self, predicate).unwrap_or(self.len()) {
+            0 => Err(Err::Error(E::from_error_kind(*self, e))),
+            n => Ok(self.take_split(n)),
+        }
+    }
+}
+
+macro_rules! slice_impl {
+    ($($r:ty),*) => {
+        $(
+            impl<'a> Slice<$r> for UVec<'a, u8> {
+                fn slice(&self, range: $r) -> Self {
+                    self.range(range)
+                }
+            }
+        )*
+    };
+}
+
+slice_impl!(Range<usize>, RangeTo<usize>, RangeFrom<usize>, RangeFull);
+
+/// Compares the start of `uv` with `t` using `eq` for every pair of bytes.
+fn compare_by<F: Fn(u8, u8) -> bool>(uv: &UVec<u8>, t: &[u8], eq: F) -> CompareResult {
+    if uv.iter().zip(t).any(|(&a, &b)| !eq(a, b)) {
+        CompareResult::Error
+    } else if uv.len() >= t.len() {
+        CompareResult::Ok
+    } else {
+        CompareResult::Incomplete
+    }
+}
+
+macro_rules! compare_impl {
+    ($([$($g:tt)*] $t:ty;)*) => {
+        $(
+            impl<'a, $($g)*> Compare<$t> for UVec<'a, u8> {
+                fn compare(&self, t: $t) -> CompareResult {
+                    compare_by(self, t.as_ref(), |a, b| a == b)
+                }
+                fn compare_no_case(&self, t: $t) -> CompareResult {
+                    compare_by(self, t.as_ref(), |a, b| a.eq_ignore_ascii_case(&b))
+                }
+            }
+        )*
+    };
+}
+
+compare_impl! {
+    ['b] &'b [u8];
+    ['b] &'b str;
+    ['b, const N: usize] &'b [u8; N];
+    [const N: usize] [u8; N];
+}
+
+impl<'a, 'b> FindSubstring<&'b [u8]> for UVec<'a, u8> {
+    fn find_substring(&self, substr: &'b [u8]) -> Option<usize> {
+        self.find_subslice(substr)
+    }
+}
+
+impl<'a, 'b> FindSubstring<&'b str> for UVec<'a, u8> {
+    fn find_substring(&self, substr: &'b str) -> Option<usize> {
+        self.find_subslice(substr.as_bytes())
+    }
+}
+
+impl<'a> FindToken<u8> for UVec<'a, u8> {
+    fn find_token(&self, token: u8) -> bool {
+        self.find_byte(token).is_some()
+    }
+}
+
+impl<'a, 'b> FindToken<&'b u8> for UVec<'a, u8> {
+    fn find_token(&self, token: &'b u8) -> bool {
+        self.find_byte(*token).is_some()
+    }
+}
+
+/// Returns the distance between the starts of the vectors, assuming `second` is a suffix of
+/// `self` as all the vectors returned by the parsers are.
+impl<'a> Offset for UVec<'a, u8> {
+    fn offset(&self, second: &Self) -> usize {
+        self.len() - second.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::bytes::complete::{is_a, tag, tag_no_case, take, take_until, take_while1};
+    use nom::bytes::streaming;
+    use nom::character::is_alphabetic;
+    use nom::combinator::recognize;
+    use nom::error::Error;
+    use nom::number::complete::{be_u16, le_u32};
+    use nom::sequence::{pair, terminated, tuple};
+
+    type Res<'a, O> = IResult<UVec<'a, u8>, O, Error<UVec<'a, u8>>>;
+    type Message<'a> = (UVec<'a, u8>, UVec<'a, u8>, u16, u32, UVec<'a, u8>);
+
+    fn message(i: UVec<u8>) -> Res<Message> {
+        tuple((
+            terminated(take_while1(is_alphabetic), tag(", ")),
+            terminated(take_until("\r\n"), tag("\r\n")),
+            be_u16,
+            le_u32,
+            take(3usize),
+        ))(i)
+    }
+
+    #[test]
+    fn combinators() {
+        let data = b"Hello, world\r\n\x01\x02\x03\x04\x05\x06abc";
+        for mid in 0..data.len() + 1 {
+            let (a, b) = data.split_at(mid);
+            let (rest, (word, line, n16, n32, abc)) = message(UVec::new((a, b))).unwrap();
+            assert_eq!(word, b"Hello"[..]);
+            assert_eq!(line, b"world"[..]);
+            assert_eq!(n16, 0x0102);
+            assert_eq!(n32, 0x06050403);
+            assert_eq!(abc, b"abc"[..]);
+            assert!(rest.is_empty());
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let uv = UVec::new((b"GE", b"T /"));
+        assert_eq!(uv.compare(&b"GET"[..]), CompareResult::Ok);
+        assert_eq!(uv.compare("GEX"), CompareResult::Error);
+        assert_eq!(uv.compare(b"GET /index"), CompareResult::Incomplete);
+        assert_eq!(uv.compare_no_case(*b"get"), CompareResult::Ok);
+        let r: Res<_> = tag_no_case("get")(uv);
+        assert_eq!(r.unwrap().0, b" /"[..]);
+        let r: Res<_> = streaming::tag("GET /x")(uv);
+        assert_eq!(r, Err(Err::Incomplete(Needed::new(1))));
+        let r: Res<_> = tag("POST")(uv);
+        assert_eq!(r, Err(Err::Error(Error::new(uv, ErrorKind::Tag))));
+    }
+
+    #[test]
+    fn tokens_and_offset() {
+        let uv = UVec::new((b"ab", b"ba-c"));
+        let r: Res<_> = is_a("ab")(uv);
+        assert_eq!(
+            r.unwrap(),
+            (UVec::new((b"-c", b"")), UVec::new((b"abba", b"")))
+        );
+        let set = UVec::new((b"a", b"b"));
+        assert!(set.find_token(b'b'));
+        assert!(!set.find_token(&b'c'));
+        let r: Res<_> = recognize(pair(tag("ab"), tag("ba")))(uv);
+        let (rest, matched) = r.unwrap();
+        assert_eq!(matched, b"abba"[..]);
+        assert_eq!(uv.offset(&rest), 4);
+        let r: Res<UVec<u8>> = streaming::take_while(|b| b != b'-')(uv);
+        assert_eq!(r.unwrap().1, b"abba"[..]);
+        let r: Res<UVec<u8>> = streaming::take_while(|b| b != b'x')(uv);
+        assert_eq!(r, Err(Err::Incomplete(Needed::new(1))));
+    }
+}
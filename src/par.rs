@@ -0,0 +1,246 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use rayon::iter::plumbing::{
+    bridge, Consumer, Producer, ProducerCallback, Reducer, UnindexedConsumer,
+};
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
+};
+
+use super::{Chunks, Iter, UVec};
+
+impl<'a, T: Sync> UVec<'a, T> {
+    /// Returns a parallel iterator over `UVec`. The work is first split at the point where the
+    /// first slice ends, the slices are then split further the same way as `rayon` splits
+    /// slices.
+    ///
+    /// ```
+    /// # extern crate rayon;
+    /// # extern crate uvector;
+    /// use rayon::prelude::*;
+    /// use uvector::UVec;
+    ///
+    /// let a: Vec<u64> = (0..1000).collect();
+    /// let b: Vec<u64> = (1000..1500).collect();
+    /// let uv = UVec::new((&a, &b));
+    /// assert_eq!(uv.par_iter().sum::<u64>(), (0..1500).sum());
+    /// assert_eq!(uv.par_iter().position_any(|&x| x == 1200), Some(1200));
+    /// ```
+    pub fn par_iter(&self) -> ParIter<'a, T> {
+        ParIter { uv: *self }
+    }
+    /// Returns a parallel iterator over chunks of `size` elements, see `chunks`. Chunks that
+    /// span both slices are never split between threads.
+    ///
+    /// ```
+    /// # extern crate rayon;
+    /// # extern crate uvector;
+    /// use rayon::prelude::*;
+    /// use uvector::UVec;
+    ///
+    /// let uv = UVec::new((&[1, 2, 3], &[4, 5]));
+    /// let sums: Vec<i32> = uv.par_chunks(2).map(|c| c.iter().sum()).collect();
+    /// assert_eq!(sums, [3, 7, 5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn par_chunks(&self, size: usize) -> ParChunks<'a, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+        ParChunks { uv: *self, size }
+    }
+}
+
+/// A parallel iterator over the elements of a `UVec`, see `UVec::par_iter`
+#[derive(Debug)]
+pub struct ParIter<'a, T: 'a> {
+    uv: UVec<'a, T>,
+}
+
+impl<'a, T> Clone for ParIter<'a, T> {
+    fn clone(&self) -> Self {
+        ParIter { uv: self.uv }
+    }
+}
+
+impl<'a, T: Sync> ParallelIterator for ParIter<'a, T> {
+    type Item = &'a T;
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<&'a T>,
+    {
+        self.drive(consumer)
+    }
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.uv.len())
+    }
+}
+
+impl<'a, T: Sync> IndexedParallelIterator for ParIter<'a, T> {
+    fn drive<C: Consumer<&'a T>>(self, consumer: C) -> C::Result {
+        let (a, b) = self.uv.s;
+        if a.is_empty() {
+            return b.par_iter().drive(consumer);
+        }
+        if b.is_empty() {
+            return a.par_iter().drive(consumer);
+        }
+        let (left, right, reducer) = consumer.split_at(a.len());
+        let (l, r) = rayon::join(|| a.par_iter().drive(left), || b.par_iter().drive(right));
+        reducer.reduce(l, r)
+    }
+    fn len(&self) -> usize {
+        self.uv.len()
+    }
+    fn with_producer<CB: ProducerCallback<&'a T>>(self, callback: CB) -> CB::Output {
+        callback.callback(IterProducer { uv: self.uv })
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for UVec<'a, T> {
+    type Iter = ParIter<'a, T>;
+    type Item = &'a T;
+    fn into_par_iter(self) -> ParIter<'a, T> {
+        ParIter { uv: self }
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for &UVec<'a, T> {
+    type Iter = ParIter<'a, T>;
+    type Item = &'a T;
+    fn into_par_iter(self) -> ParIter<'a, T> {
+        self.par_iter()
+    }
+}
+
+struct IterProducer<'a, T: 'a> {
+    uv: UVec<'a, T>,
+}
+
+impl<'a, T: Sync> Producer for IterProducer<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Iter<'a, T> {
+        self.uv.iter()
+    }
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (l, r) = self.uv.split_at(index);
+        (IterProducer { uv: l }, IterProducer { uv: r })
+    }
+}
+
+/// A parallel iterator over a `UVec` in non-overlapping chunks, see `UVec::par_chunks`
+#[derive(Debug)]
+pub struct ParChunks<'a, T: 'a> {
+    uv: UVec<'a, T>,
+    size: usize,
+}
+
+impl<'a, T> Clone for ParChunks<'a, T> {
+    fn clone(&self) -> Self {
+        ParChunks {
+            uv: self.uv,
+            size: self.size,
+        }
+    }
+}
+
+impl<'a, T: Sync> ParallelIterator for ParChunks<'a, T> {
+    type Item = UVec<'a, T>;
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<UVec<'a, T>>,
+    {
+        bridge(self, consumer)
+    }
+    fn opt_len(&self) -> Option<usize> {
+        Some(IndexedParallelIterator::len(self))
+    }
+}
+
+impl<'a, T: Sync> IndexedParallelIterator for ParChunks<'a, T> {
+    fn drive<C: Consumer<UVec<'a, T>>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+    fn len(&self) -> usize {
+        self.uv.len().div_ceil(self.size)
+    }
+    fn with_producer<CB: ProducerCallback<UVec<'a, T>>>(self, callback: CB) -> CB::Output {
+        callback.callback(ChunksProducer {
+            uv: self.uv,
+            size: self.size,
+        })
+    }
+}
+
+struct ChunksProducer<'a, T: 'a> {
+    uv: UVec<'a, T>,
+    size: usize,
+}
+
+impl<'a, T: Sync> Producer for ChunksProducer<'a, T> {
+    type Item = UVec<'a, T>;
+    type IntoIter = Chunks<'a, T>;
+    fn into_iter(self) -> Chunks<'a, T> {
+        Chunks::new(self.uv, self.size)
+    }
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.uv.len().min(index * self.size);
+        let (l, r) = self.uv.split_at(mid);
+        (
+            ChunksProducer {
+                uv: l,
+                size: self.size,
+            },
+            ChunksProducer {
+                uv: r,
+                size: self.size,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn par_iter() {
+        let data: Vec<u32> = (0..1000).collect();
+        for &mid in &[0, 1, 333, 999, 1000] {
+            let (a, b) = data.split_at(mid);
+            let uv = UVec::new((a, b));
+            assert_eq!(uv.par_iter().len(), 1000);
+            let v: Vec<u32> = uv.par_iter().map(|x| x * 2).collect();
+            assert_eq!(v, data.iter().map(|x| x * 2).collect::<Vec<_>>());
+            let rev: Vec<u32> = uv.par_iter().rev().cloned().collect();
+            assert_eq!(rev.first(), Some(&999));
+            assert_eq!(uv.par_iter().position_first(|&x| x == 500), Some(500));
+            let zipped: u32 = uv.par_iter().zip(&uv).map(|(a, b)| a * b).max().unwrap();
+            assert_eq!(zipped, 999 * 999);
+            let filtered = (&uv).into_par_iter().filter(|&&x| x % 3 == 0).count();
+            assert_eq!(filtered, 334);
+        }
+        assert_eq!(UVec::<u8>::empty().into_par_iter().count(), 0);
+    }
+
+    #[test]
+    fn par_chunks() {
+        let data: Vec<u32> = (0..100).collect();
+        for &mid in &[0, 7, 50, 100] {
+            let (a, b) = data.split_at(mid);
+            let uv = UVec::new((a, b));
+            let chunks = uv.par_chunks(7);
+            assert_eq!(chunks.len(), 15);
+            let v: Vec<Vec<u32>> = chunks.map(|c| c.to_vec()).collect();
+            let expected: Vec<Vec<u32>> = data.chunks(7).map(|c| c.to_vec()).collect();
+            assert_eq!(v, expected);
+            let sums: Vec<u32> = uv.par_chunks(10).rev().map(|c| c.iter().sum()).collect();
+            assert_eq!(sums[0], (90..100).sum::<u32>());
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use core::fmt;
+use core::ops::{Bound, Range, RangeBounds};
+
+/// The error returned when a range is not contained within a vector.
+///
+/// The `Display` implementation produces the same messages as the panics of std slices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeError {
+    /// The start of the range is greater than the length of the vector
+    StartOutOfRange { index: usize, len: usize },
+    /// The end of the range is greater than the length of the vector
+    EndOutOfRange { index: usize, len: usize },
+    /// The start of the range is greater than its end
+    StartAfterEnd { start: usize, end: usize },
+    /// The range starts after `usize::MAX`
+    StartOverflow,
+    /// The range ends after `usize::MAX`
+    EndOverflow,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RangeError::StartOutOfRange { index, len } => write!(
+                f,
+                "range start index {} out of range for slice of length {}",
+                index, len
+            ),
+            RangeError::EndOutOfRange { index, len } => write!(
+                f,
+                "range end index {} out of range for slice of length {}",
+                index, len
+            ),
+            RangeError::StartAfterEnd { start, end } => {
+                write!(f, "slice index starts at {} but ends at {}", start, end)
+            }
+            RangeError::StartOverflow => {
+                f.write_str("attempted to index slice from after maximum usize")
+            }
+            RangeError::EndOverflow => f.write_str("attempted to index slice up to maximum usize"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RangeError {}
+
+/// Converts `range` into `start..end` checking that it is contained within a vector of length
+/// `len`.
+pub(crate) fn bounds<R: RangeBounds<usize>>(
+    range: R,
+    len: usize,
+) -> Result<Range<usize>, RangeError> {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.checked_add(1).ok_or(RangeError::StartOverflow)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1).ok_or(RangeError::EndOverflow)?,
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => {
+            if start > len {
+                return Err(RangeError::StartOutOfRange { index: start, len });
+            }
+            len
+        }
+    };
+    if start > end {
+        Err(RangeError::StartAfterEnd { start, end })
+    } else if end > len {
+        Err(RangeError::EndOutOfRange { index: end, len })
+    } else {
+        Ok(start..end)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn range_bounds() {
+        assert_eq!(bounds(.., 5), Ok(0..5));
+        assert_eq!(bounds(2.., 5), Ok(2..5));
+        assert_eq!(bounds(..=2, 5), Ok(0..3));
+        assert_eq!(bounds(1..4, 5), Ok(1..4));
+        assert_eq!(bounds(5..5, 5), Ok(5..5));
+        assert_eq!(
+            bounds(6.., 5),
+            Err(RangeError::StartOutOfRange { index: 6, len: 5 })
+        );
+        assert_eq!(
+            bounds(..=5, 5),
+            Err(RangeError::EndOutOfRange { index: 6, len: 5 })
+        );
+        let (start, end) = (3, 2);
+        assert_eq!(
+            bounds(start..end, 5),
+            Err(RangeError::StartAfterEnd { start: 3, end: 2 })
+        );
+        assert_eq!(
+            bounds(start + 4..end + 4, 5).unwrap_err().to_string(),
+            "slice index starts at 7 but ends at 6"
+        );
+        assert_eq!(bounds(..=usize::MAX, 3), Err(RangeError::EndOverflow));
+        assert_eq!(
+            RangeError::EndOverflow.to_string(),
+            "attempted to index slice up to maximum usize"
+        );
+        let after_max = (Bound::Excluded(usize::MAX), Bound::Unbounded);
+        assert_eq!(bounds(after_max, 3), Err(RangeError::StartOverflow));
+        assert_eq!(
+            RangeError::StartOverflow.to_string(),
+            "attempted to index slice from after maximum usize"
+        );
+    }
+}
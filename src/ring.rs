@@ -0,0 +1,190 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use alloc::collections::VecDeque;
+use core::ops::RangeBounds;
+
+use super::{UVec, UVecMut};
+
+/// Fixed-capacity ring buffer that gives access to its content through `UVec` views.
+///
+/// The buffer never reallocates after it was created. Values can be added at both ends, either
+/// failing when the buffer is full or overwriting the oldest value on the opposite end.
+///
+/// # Examples
+///
+/// ```
+/// use uvector::RingBuffer;
+///
+/// let mut rb = RingBuffer::new(4);
+/// for i in 0..6 {
+///     rb.push_back_overwrite(i);
+/// }
+/// assert_eq!(rb.view(), [2, 3, 4, 5]);
+/// assert_eq!(rb.view_range(1..3), [3, 4]);
+/// assert_eq!(rb.push_back(6), Err(6));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RingBuffer<T> {
+    buf: VecDeque<T>,
+    cap: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// Constructs a new empty `RingBuffer<T>` that can hold up to `capacity` values
+    pub fn new(capacity: usize) -> Self {
+        RingBuffer {
+            buf: VecDeque::with_capacity(capacity),
+            cap: capacity,
+        }
+    }
+    /// Constructs a full `RingBuffer<T>` with the capacity equal to the number of values in `buf`
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_full(buf: VecDeque<T>) -> Self {
+        let cap = buf.len();
+        RingBuffer { buf, cap }
+    }
+    /// Returns the maximum number of values the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+    /// Returns the number of values in the buffer.
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+    /// Returns `true` if the buffer contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+    /// Returns `true` if the buffer contains `capacity` values.
+    pub fn is_full(&self) -> bool {
+        self.buf.len() == self.cap
+    }
+    /// Removes all values from the buffer.
+    pub fn clear(&mut self) {
+        self.buf.clear()
+    }
+    /// Appends a value to the back of the buffer. If the buffer is full the value is returned
+    /// back as an error.
+    pub fn push_back(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.buf.push_back(value);
+        Ok(())
+    }
+    /// Prepends a value to the front of the buffer. If the buffer is full the value is returned
+    /// back as an error.
+    pub fn push_front(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.buf.push_front(value);
+        Ok(())
+    }
+    /// Appends a value to the back of the buffer. If the buffer is full the oldest value is
+    /// removed from the front of the buffer and returned.
+    pub fn push_back_overwrite(&mut self, value: T) -> Option<T> {
+        if self.cap == 0 {
+            return Some(value);
+        }
+        let old = if self.is_full() {
+            self.buf.pop_front()
+        } else {
+            None
+        };
+        self.buf.push_back(value);
+        old
+    }
+    /// Prepends a value to the front of the buffer. If the buffer is full the value at the back
+    /// of the buffer is removed and returned.
+    pub fn push_front_overwrite(&mut self, value: T) -> Option<T> {
+        if self.cap == 0 {
+            return Some(value);
+        }
+        let old = if self.is_full() {
+            self.buf.pop_back()
+        } else {
+            None
+        };
+        self.buf.push_front(value);
+        old
+    }
+    /// Removes the first value and returns it, or `None` if the buffer is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.buf.pop_front()
+    }
+    /// Removes the last value and returns it, or `None` if the buffer is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.buf.pop_back()
+    }
+    /// Returns a read-only view of the buffer content.
+    pub fn view(&self) -> UVec<'_, T> {
+        UVec::new(self.buf.as_slices())
+    }
+    /// Returns a read-only view of the specified range of the buffer content.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified range is not contained within the buffer.
+    pub fn view_range<R: RangeBounds<usize>>(&self, range: R) -> UVec<'_, T> {
+        self.view().range(range)
+    }
+    /// Returns a mutable view of the buffer content.
+    pub fn view_mut(&mut self) -> UVecMut<'_, T> {
+        UVecMut::new(self.buf.as_mut_slices())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        let mut rb = RingBuffer::new(3);
+        assert!(rb.is_empty());
+        assert_eq!(rb.push_back(2), Ok(()));
+        assert_eq!(rb.push_front(1), Ok(()));
+        assert_eq!(rb.push_back(3), Ok(()));
+        assert!(rb.is_full());
+        assert_eq!(rb.push_back(4), Err(4));
+        assert_eq!(rb.push_front(0), Err(0));
+        assert_eq!(rb.view(), [1, 2, 3]);
+        assert_eq!(rb.pop_front(), Some(1));
+        assert_eq!(rb.pop_back(), Some(3));
+        assert_eq!(rb.len(), 1);
+        rb.clear();
+        assert_eq!(rb.pop_back(), None);
+    }
+
+    #[test]
+    fn overwrite() {
+        let mut rb = RingBuffer::new(3);
+        assert_eq!(rb.push_back_overwrite(1), None);
+        assert_eq!(rb.push_back_overwrite(2), None);
+        assert_eq!(rb.push_back_overwrite(3), None);
+        assert_eq!(rb.push_back_overwrite(4), Some(1));
+        assert_eq!(rb.view(), [2, 3, 4]);
+        assert_eq!(rb.push_front_overwrite(1), Some(4));
+        assert_eq!(rb.view(), [1, 2, 3]);
+        assert_eq!(rb.capacity(), 3);
+        let mut empty = RingBuffer::new(0);
+        assert_eq!(empty.push_back_overwrite(1), Some(1));
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn views() {
+        let mut rb = RingBuffer::new(5);
+        for i in 0..8 {
+            rb.push_back_overwrite(i);
+        }
+        assert_eq!(rb.view_range(1..4), [4, 5, 6]);
+        rb.view_mut().reverse();
+        assert_eq!(rb.view(), [7, 6, 5, 4, 3]);
+        rb.view_mut()[0] = 0;
+        assert_eq!(rb.view().first(), Some(&0));
+    }
+}
@@ -0,0 +1,358 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+
+use memchr::{memchr, memmem, memrchr};
+
+use super::{AsSegments, UVec};
+
+impl<'a, T> UVec<'a, T> {
+    /// Binary searches a sorted vector for the given element. Returns `Ok` with the index of the
+    /// matching element, or `Err` with the index where the element could be inserted keeping the
+    /// vector sorted. See `binary_search_by` for details.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((&[1, 3, 5], &[7, 9]));
+    /// assert_eq!(uv.binary_search(&7), Ok(3));
+    /// assert_eq!(uv.binary_search(&6), Err(3));
+    /// assert_eq!(uv.binary_search(&0), Err(0));
+    /// ```
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|p| p.cmp(x))
+    }
+    /// Binary searches a sorted vector with a comparator function. The comparator is first called
+    /// for the first element of the second slice to pick the slice to search in, the search is
+    /// then delegated to the slice `binary_search_by`. Returned indices are logical indices in
+    /// the vector.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> Ordering,
+    {
+        let (a, b) = self.s;
+        match b.first() {
+            Some(first) if f(first) != Ordering::Greater => b
+                .binary_search_by(f)
+                .map(|i| i + a.len())
+                .map_err(|i| i + a.len()),
+            _ => a.binary_search_by(f),
+        }
+    }
+    /// Binary searches a sorted vector with a key extraction function, see `binary_search_by`.
+    pub fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+    {
+        self.binary_search_by(|k| f(k).cmp(b))
+    }
+    /// Returns the index of the partition point according to the given predicate, i.e. the index
+    /// of the first element for which the predicate returns `false`. The vector is assumed to be
+    /// partitioned, so that all elements for which the predicate returns `true` are at the start.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((&[1, 2, 3], &[4, 5, 6]));
+    /// assert_eq!(uv.partition_point(|&x| x < 5), 4);
+    /// assert_eq!(uv.partition_point(|&x| x < 2), 1);
+    /// ```
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (a, b) = self.s;
+        match b.first() {
+            Some(first) if pred(first) => a.len() + b.partition_point(pred),
+            _ => a.partition_point(pred),
+        }
+    }
+}
+
+impl<'a> UVec<'a, u8> {
+    /// Returns the index of the first occurrence of `byte` in the vector.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((b"GET / HTTP/1.1\r", b"\nHost: a\r\n"));
+    /// assert_eq!(uv.find_byte(b'\n'), Some(15));
+    /// assert_eq!(uv.rfind_byte(b'\n'), Some(24));
+    /// ```
+    pub fn find_byte(&self, byte: u8) -> Option<usize> {
+        match memchr(byte, self.s.0) {
+            Some(i) => Some(i),
+            None => memchr(byte, self.s.1).map(|i| i + self.s.0.len()),
+        }
+    }
+    /// Returns the index of the last occurrence of `byte` in the vector.
+    pub fn rfind_byte(&self, byte: u8) -> Option<usize> {
+        match memrchr(byte, self.s.1) {
+            Some(i) => Some(i + self.s.0.len()),
+            None => memrchr(byte, self.s.0),
+        }
+    }
+    /// Returns the index of the first occurrence of `needle` in the vector. The match may start
+    /// in the first slice and end in the second one, and the needle may be split as well. An
+    /// empty `needle` matches at index 0.
+    ///
+    /// Takes linear time with the `alloc` feature. Without it, a split needle takes `O(n * m)`
+    /// time for a vector of length `n` and a needle of length `m`, and a contiguous needle
+    /// longer than 33 bytes takes `O(m^2)` time for matches across the seam.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((b"HTTP/1.1 200 OK\r\n\r", b"\n<html>"));
+    /// let end = uv.find_subslice(b"\r\n\r\n").unwrap();
+    /// assert_eq!(end, 15);
+    /// assert_eq!(uv.range(end + 4..), b"<html>"[..]);
+    /// ```
+    pub fn find_subslice<S: AsSegments<u8> + ?Sized>(&self, needle: &S) -> Option<usize> {
+        self.find_needle(&Needle::new(needle.as_uvec()))
+    }
+    /// Returns an iterator over the indices of non-overlapping occurrences of `needle` in the
+    /// vector, see `find_subslice`.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((b"a,b,", b",c"));
+    /// assert_eq!(uv.find_iter(b",").collect::<Vec<_>>(), [1, 3, 4]);
+    /// ```
+    pub fn find_iter<'n, S>(&self, needle: &'n S) -> FindIter<'a, 'n>
+    where
+        S: AsSegments<u8> + ?Sized,
+    {
+        FindIter {
+            uv: *self,
+            needle: Needle::new(needle.as_uvec()),
+            pos: 0,
+        }
+    }
+    /// Without an allocator a split needle can't be joined, so every match of its first slice
+    /// is checked for the rest, which takes `O(n * m)` time in the worst case.
+    fn find_needle(&self, needle: &Needle) -> Option<usize> {
+        if needle.rest.is_empty() {
+            return self.find_with(&needle.finder);
+        }
+        let whole = UVec::new((needle.finder.needle(), needle.rest));
+        let mut pos = 0;
+        while let Some(i) = self.range(pos..).find_with(&needle.finder) {
+            if self.range(pos + i..).starts_with(&whole) {
+                return Some(pos + i);
+            }
+            pos += i + 1;
+        }
+        None
+    }
+    fn find_with(&self, finder: &memmem::Finder) -> Option<usize> {
+        let (a, b) = self.s;
+        if let Some(i) = finder.find(a) {
+            return Some(i);
+        }
+        // matches starting in the first slice and ending in the second one can only use the
+        // last `n - 1` values of the first slice, the needle can't be empty here as an empty
+        // needle always matches at the start of the first slice
+        let n = finder.needle().len();
+        let tail = &a[a.len() - a.len().min(n - 1)..];
+        let head = &b[..b.len().min(n - 1)];
+        if let Some(i) = find_seam(finder, tail, head) {
+            return Some(a.len() - tail.len() + i);
+        }
+        finder.find(b).map(|i| i + a.len())
+    }
+}
+
+/// Length of the stack buffer joining the two sides of the seam
+const SEAM_BUF: usize = 64;
+
+/// Returns the index in `tail` of the first match that starts in `tail` and ends in `head`. The
+/// sides are joined on the stack for short needles.
+fn find_seam(finder: &memmem::Finder, tail: &[u8], head: &[u8]) -> Option<usize> {
+    let len = tail.len() + head.len();
+    if len > SEAM_BUF {
+        return find_seam_long(finder, tail, head);
+    }
+    let mut buf = [0; SEAM_BUF];
+    buf[..tail.len()].copy_from_slice(tail);
+    buf[tail.len()..len].copy_from_slice(head);
+    finder.find(&buf[..len]).filter(|&i| i < tail.len())
+}
+
+#[cfg(feature = "alloc")]
+fn find_seam_long(finder: &memmem::Finder, tail: &[u8], head: &[u8]) -> Option<usize> {
+    let buf = [tail, head].concat();
+    finder.find(&buf).filter(|&i| i < tail.len())
+}
+
+/// Without an allocator every start in `tail` is compared with the needle, which takes
+/// `O(m^2)` time for a needle of length `m`.
+#[cfg(not(feature = "alloc"))]
+fn find_seam_long(finder: &memmem::Finder, tail: &[u8], head: &[u8]) -> Option<usize> {
+    let needle = finder.needle();
+    (0..tail.len()).find(|&p| {
+        let k = tail.len() - p;
+        tail[p..] == needle[..k] && head.starts_with(&needle[k..])
+    })
+}
+
+/// A needle prepared for searching. A split needle is joined if an allocator is available,
+/// otherwise `finder` looks for its first slice and `rest` holds the second one.
+#[derive(Debug, Clone)]
+struct Needle<'n> {
+    finder: memmem::Finder<'n>,
+    rest: &'n [u8],
+}
+
+impl<'n> Needle<'n> {
+    #[cfg(feature = "alloc")]
+    fn new(needle: UVec<'n, u8>) -> Self {
+        let finder = match needle.as_single_slice() {
+            Some(n) => memmem::Finder::new(n),
+            None => memmem::Finder::new(&needle.to_vec()).into_owned(),
+        };
+        Needle { finder, rest: &[] }
+    }
+    #[cfg(not(feature = "alloc"))]
+    fn new(needle: UVec<'n, u8>) -> Self {
+        let (first, rest) = match needle.as_single_slice() {
+            Some(n) => (n, &[][..]),
+            None => needle.s,
+        };
+        Needle {
+            finder: memmem::Finder::new(first),
+            rest,
+        }
+    }
+    fn len(&self) -> usize {
+        self.finder.needle().len() + self.rest.len()
+    }
+}
+
+/// An iterator over the indices of non-overlapping occurrences of a byte string in a `UVec<u8>`,
+/// see `UVec::find_iter`
+#[derive(Debug, Clone)]
+pub struct FindIter<'a, 'n> {
+    uv: UVec<'a, u8>,
+    needle: Needle<'n>,
+    pos: usize,
+}
+
+impl<'a, 'n> Iterator for FindIter<'a, 'n> {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        if self.pos > self.uv.len() {
+            return None;
+        }
+        match self.uv.range(self.pos..).find_needle(&self.needle) {
+            Some(i) => {
+                let found = self.pos + i;
+                self.pos = found + self.needle.len().max(1);
+                Some(found)
+            }
+            None => {
+                self.pos = self.uv.len() + 1;
+                None
+            }
+        }
+    }
+}
+
+impl<'a, 'n> FusedIterator for FindIter<'a, 'n> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn binary_search() {
+        let data = [1, 2, 2, 4, 7, 7, 9];
+        for mid in 0..data.len() + 1 {
+            let (a, b) = data.split_at(mid);
+            let uv = UVec::new((a, b));
+            for x in 0..11 {
+                match uv.binary_search(&x) {
+                    Ok(i) => assert_eq!(uv[i], x),
+                    Err(i) => assert_eq!(Err(i), data.binary_search(&x)),
+                }
+                assert_eq!(
+                    uv.partition_point(|&v| v < x),
+                    data.partition_point(|&v| v < x)
+                );
+            }
+        }
+        let pairs = UVec::new((&[(1, 'a'), (3, 'b')], &[(5, 'c')]));
+        assert_eq!(pairs.binary_search_by_key(&5, |p| p.0), Ok(2));
+        assert_eq!(pairs.binary_search_by_key(&4, |p| p.0), Err(2));
+    }
+
+    #[test]
+    fn find_byte() {
+        let uv = UVec::new((b"abc", b"dcb"));
+        assert_eq!(uv.find_byte(b'c'), Some(2));
+        assert_eq!(uv.find_byte(b'd'), Some(3));
+        assert_eq!(uv.find_byte(b'x'), None);
+        assert_eq!(uv.rfind_byte(b'b'), Some(5));
+        assert_eq!(uv.rfind_byte(b'a'), Some(0));
+        assert_eq!(uv.rfind_byte(b'x'), None);
+    }
+
+    #[test]
+    fn find_subslice_seam() {
+        let data = b"xx\r\n\r\nyy";
+        for mid in 0..data.len() + 1 {
+            let (a, b) = data.split_at(mid);
+            let uv = UVec::new((a, b));
+            assert_eq!(uv.find_subslice(b"\r\n\r\n"), Some(2), "split at {}", mid);
+            assert_eq!(uv.find_subslice(b"yy"), Some(6));
+            assert_eq!(uv.find_subslice(b"xx\r\n\r\nyy"), Some(0));
+            assert_eq!(uv.find_subslice(b"\r\n\r\r"), None);
+            assert_eq!(uv.find_subslice(b"xx\r\n\r\nyyy"), None);
+            assert_eq!(uv.find_subslice(b""), Some(0));
+            assert_eq!(uv.find_subslice(&UVec::new((b"\r\n", b"\r\ny"))), Some(2));
+            assert_eq!(uv.find_subslice(&UVec::new((b"x\r", b"\r"))), None);
+        }
+    }
+
+    #[test]
+    fn find_subslice_long_needle() {
+        let mut data = [b'a'; 300];
+        data[250] = b'b';
+        let needle = &data[100..251];
+        for &mid in &[0, 100, 150, 200, 250, 251, 300] {
+            let (a, b) = data.split_at(mid);
+            let uv = UVec::new((a, b));
+            assert_eq!(uv.find_subslice(needle), Some(100), "split at {}", mid);
+            assert_eq!(uv.find_subslice(&data[..]), Some(0));
+            assert_eq!(uv.find_subslice(&data[249..]), Some(249));
+            assert_eq!(uv.find_subslice(&[b'b'; 2][..]), None);
+        }
+    }
+
+    #[test]
+    fn find_iter() {
+        let data = b"abab_ab_aab";
+        for mid in 0..data.len() + 1 {
+            let (a, b) = data.split_at(mid);
+            let uv = UVec::new((a, b));
+            assert_eq!(uv.find_iter(b"ab").collect::<Vec<_>>(), [0, 2, 5, 9]);
+            assert_eq!(uv.find_iter(b"aa").collect::<Vec<_>>(), [8]);
+        }
+        let uv = UVec::new((b"aa", b"aa"));
+        assert_eq!(uv.find_iter(b"aa").collect::<Vec<_>>(), [0, 2]);
+        assert_eq!(uv.find_iter(b"").count(), 5);
+        assert_eq!(uv.find_iter(b"b").next(), None);
+        let data = b"xyz_xy|zxyz";
+        let needle = UVec::new((b"x", b"yz"));
+        for mid in 0..data.len() + 1 {
+            let (a, b) = data.split_at(mid);
+            let uv = UVec::new((a, b));
+            assert_eq!(uv.find_iter(&needle).collect::<Vec<_>>(), [0, 8]);
+            assert_eq!(uv.find_iter(&UVec::new((b"xy", b"|z"))).count(), 1);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use core::ops::{Index, Range, RangeBounds};
+use core::slice;
+
+use super::{range, RangeError, UVec};
+
+/// Read-only array type allowing access `N` slices as a single continuous vector.
+///
+/// This is a generalization of `UVec` for data scattered across more than two buffers. Indexing
+/// is done in O(log N) time using a table of cumulative segment lengths.
+///
+/// # Examples
+///
+/// ```
+/// use uvector::USegments;
+///
+/// let us = USegments::new([&[1, 2][..], &[3], &[], &[4, 5, 6]]);
+/// assert_eq!(us.len(), 6);
+/// assert_eq!(us[2], 3);
+/// assert_eq!(us[3], 4);
+/// let sub = us.range(1..4); // that will only contain [2, 3, 4]
+/// assert_eq!(sub.iter().sum::<i32>(), 9);
+/// ```
+#[derive(Debug)]
+pub struct USegments<'a, T: 'a, const N: usize> {
+    s: [&'a [T]; N],
+    ends: [usize; N],
+}
+
+impl<'a, T, const N: usize> USegments<'a, T, N> {
+    /// Constructs a new `USegments<T, N>` from an array of slices
+    pub fn new(s: [&'a [T]; N]) -> Self {
+        let mut ends = [0; N];
+        let mut total = 0;
+        for (end, seg) in ends.iter_mut().zip(s.iter()) {
+            total += seg.len();
+            *end = total;
+        }
+        USegments { s, ends }
+    }
+    /// Constructs a new empty `USegments<T, N>`
+    pub fn empty() -> Self {
+        Self::new([&[]; N])
+    }
+    /// Returns the segments the vector consists of.
+    pub fn segments(&self) -> [&'a [T]; N] {
+        self.s
+    }
+    /// Returns the length of the vector. The length is determined as the sum of lengths of all the
+    /// segments.
+    pub fn len(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+    /// Returns `true` if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns iterator over `USegments`
+    pub fn iter(&self) -> SegmentsIter<'a, T, N> {
+        SegmentsIter {
+            s: self.s,
+            next: 0,
+            cur: [].iter(),
+        }
+    }
+    /// Returns a new `USegments` that only includes the values from the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified range is not contained within the `USegments`
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        match self.try_range(range) {
+            Ok(us) => us,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    /// Returns a new `USegments` that only includes the values from the specified range, or an
+    /// error describing why the range is not contained within the `USegments`.
+    pub fn try_range<R: RangeBounds<usize>>(&self, range: R) -> Result<Self, RangeError> {
+        let Range { start, end } = range::bounds(range, self.len())?;
+        let mut s = self.s;
+        for (i, seg) in s.iter_mut().enumerate() {
+            let seg_start = self.seg_start(i);
+            let seg_end = self.ends[i];
+            let lo = start.clamp(seg_start, seg_end) - seg_start;
+            let hi = end.clamp(seg_start, seg_end) - seg_start;
+            *seg = &seg[lo..hi];
+        }
+        Ok(Self::new(s))
+    }
+    fn seg_start(&self, seg: usize) -> usize {
+        if seg == 0 {
+            0
+        } else {
+            self.ends[seg - 1]
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Clone for USegments<'a, T, N> {
+    fn clone(&self) -> Self {
+        USegments {
+            s: self.s,
+            ends: self.ends,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Index<usize> for USegments<'a, T, N> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        let seg = self.ends.partition_point(|&end| end <= index);
+        assert!(
+            seg < N,
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            index
+        );
+        &self.s[seg][index - self.seg_start(seg)]
+    }
+}
+
+impl<'a, T> From<UVec<'a, T>> for USegments<'a, T, 2> {
+    fn from(uv: UVec<'a, T>) -> Self {
+        Self::new([uv.s.0, uv.s.1])
+    }
+}
+
+impl<'a, T> From<USegments<'a, T, 2>> for UVec<'a, T> {
+    fn from(us: USegments<'a, T, 2>) -> Self {
+        UVec::new((us.s[0], us.s[1]))
+    }
+}
+
+/// An iterator over the elements of a `USegments`
+#[derive(Debug)]
+pub struct SegmentsIter<'a, T: 'a, const N: usize> {
+    s: [&'a [T]; N],
+    next: usize,
+    cur: slice::Iter<'a, T>,
+}
+
+impl<'a, T, const N: usize> Iterator for SegmentsIter<'a, T, N> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(v) = self.cur.next() {
+                return Some(v);
+            }
+            if self.next == N {
+                return None;
+            }
+            self.cur = self.s[self.next].iter();
+            self.next += 1;
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.cur.len() + self.s[self.next..].iter().map(|s| s.len()).sum::<usize>();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for SegmentsIter<'a, T, N> {}
+
+impl<'a, T, const N: usize> IntoIterator for USegments<'a, T, N> {
+    type Item = &'a T;
+    type IntoIter = SegmentsIter<'a, T, N>;
+    fn into_iter(self) -> SegmentsIter<'a, T, N> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &USegments<'a, T, N> {
+    type Item = &'a T;
+    type IntoIter = SegmentsIter<'a, T, N>;
+    fn into_iter(self) -> SegmentsIter<'a, T, N> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn index() {
+        let us = USegments::new([&[5, 10][..], &[], &[15], &[20, 25]]);
+        assert_eq!(us.len(), 5);
+        assert_eq!(us[0], 5);
+        assert_eq!(us[1], 10);
+        assert_eq!(us[2], 15);
+        assert_eq!(us[3], 20);
+        assert_eq!(us[4], 25);
+        let empty: USegments<i32, 3> = USegments::empty();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_outofrange() {
+        let us = USegments::new([&[1, 2][..], &[3]]);
+        let _r = us[3];
+    }
+
+    #[test]
+    fn subrange() {
+        let us = USegments::new([&[1, 2, 3][..], &[4], &[5, 6]]);
+        let r = us.range(2..5);
+        assert_eq!(r.len(), 3);
+        assert_eq!(r.iter().cloned().collect::<Vec<_>>(), [3, 4, 5]);
+        assert_eq!(r.segments(), [&[3][..], &[4], &[5]]);
+        assert_eq!(us.range(3..4)[0], 4);
+        assert!(us.range(6..6).is_empty());
+    }
+
+    #[test]
+    fn iter() {
+        let us = USegments::new([&[][..], &[1, 2], &[], &[3]]);
+        let mut it = us.iter();
+        assert_eq!(it.len(), 3);
+        it.next();
+        assert_eq!(it.len(), 2);
+        assert_eq!(us.into_iter().sum::<i32>(), 6);
+    }
+
+    #[test]
+    fn uvec_conversion() {
+        let uv = UVec::new((&[1, 2], &[3]));
+        let us = USegments::from(uv);
+        assert_eq!(us[2], 3);
+        let back = UVec::from(us.range(1..3));
+        assert_eq!(back.len(), 2);
+        assert_eq!(back[0], 2);
+    }
+}
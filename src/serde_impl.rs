@@ -0,0 +1,181 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+#[cfg(feature = "alloc")]
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+#[cfg(feature = "alloc")]
+use super::RingBuffer;
+use super::{USegments, UVec, UVecMut};
+
+/// Serializes the vector as a single sequence, the same way as a slice with the same content.
+impl<'a, T: Serialize> Serialize for UVec<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'a, T: Serialize> Serialize for UVecMut<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_uvec().serialize(serializer)
+    }
+}
+
+impl<'a, T: Serialize, const N: usize> Serialize for USegments<'a, T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'a> UVec<'a, u8> {
+    /// Returns a wrapper serializing the vector as a byte string, see `Bytes`.
+    ///
+    /// ```
+    /// # use uvector::UVec;
+    /// let uv = UVec::new((b"abc", b""));
+    /// serde_test::assert_ser_tokens(&uv.as_bytes(), &[serde_test::Token::Bytes(b"abc")]);
+    /// ```
+    pub fn as_bytes(&self) -> Bytes<'a> {
+        Bytes(*self)
+    }
+}
+
+/// A wrapper around `UVec<u8>` that serializes it using `serialize_bytes` if the content is in a
+/// single slice and as a sequence of `u8` otherwise, see `UVec::as_bytes`
+///
+/// Serializers that don't support borrowed byte strings spanning two slices, like `bincode`,
+/// encode both forms the same way.
+#[derive(Debug, Clone)]
+pub struct Bytes<'a>(pub UVec<'a, u8>);
+
+impl<'a> Serialize for Bytes<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0.as_single_slice() {
+            Some(s) => serializer.serialize_bytes(s),
+            None => {
+                let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+                for b in self.0.iter() {
+                    seq.serialize_element(b)?;
+                }
+                seq.end()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Serialize> Serialize for RingBuffer<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.view().serialize(serializer)
+    }
+}
+
+/// Deserializes a `RingBuffer` from a sequence. The capacity of the buffer is set to the number
+/// of values in the sequence, so the returned buffer is full.
+#[cfg(feature = "alloc")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for RingBuffer<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(RingBufferVisitor(PhantomData))
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct RingBufferVisitor<T>(PhantomData<T>);
+
+#[cfg(feature = "alloc")]
+impl<'de, T: Deserialize<'de>> Visitor<'de> for RingBufferVisitor<T> {
+    type Value = RingBuffer<T>;
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<RingBuffer<T>, A::Error> {
+        // don't trust the size hint too much, same as serde does for VecDeque
+        let mut buf = VecDeque::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(v) = seq.next_element()? {
+            buf.push_back(v);
+        }
+        Ok(RingBuffer::from_full(buf))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_test::{assert_ser_tokens, Token};
+    use std::vec::Vec;
+
+    #[test]
+    fn round_trip() {
+        let data = [1, 2, 3, 4, 5];
+        for mid in 0..data.len() + 1 {
+            let (a, b) = data.split_at(mid);
+            let json = serde_json::to_string(&UVec::new((a, b))).unwrap();
+            assert_eq!(json, "[1,2,3,4,5]");
+            assert_eq!(serde_json::from_str::<Vec<i32>>(&json).unwrap(), data);
+        }
+        let json = serde_json::to_string(&UVec::<i32>::empty()).unwrap();
+        assert!(serde_json::from_str::<Vec<i32>>(&json).unwrap().is_empty());
+        let (mut a, mut b) = (["a", "b"], ["c"]);
+        let json = serde_json::to_string(&UVecMut::new((&mut a, &mut b))).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<&str>>(&json).unwrap(),
+            ["a", "b", "c"]
+        );
+        let json = serde_json::to_string(&USegments::new([&[1][..], &[2, 3], &[]])).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<i32>>(&json).unwrap(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn seq_tokens() {
+        assert_ser_tokens(
+            &UVec::new((&[1u16], &[2])),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::U16(1),
+                Token::U16(2),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn bytes() {
+        let uv = UVec::new((b"ab", b"c"));
+        assert_ser_tokens(
+            &uv.as_bytes(),
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U8(b'a'),
+                Token::U8(b'b'),
+                Token::U8(b'c'),
+                Token::SeqEnd,
+            ],
+        );
+        assert_ser_tokens(&uv.range(..2).as_bytes(), &[Token::Bytes(b"ab")]);
+        assert_ser_tokens(&uv.range(2..).as_bytes(), &[Token::Bytes(b"c")]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn ring_buffer() {
+        let mut rb = RingBuffer::new(3);
+        for i in 0..5 {
+            rb.push_back_overwrite(i);
+        }
+        let json = serde_json::to_string(&rb).unwrap();
+        assert_eq!(json, "[2,3,4]");
+        let rb: RingBuffer<i32> = serde_json::from_str("[1,2]").unwrap();
+        assert_eq!(rb.capacity(), 2);
+        assert!(rb.is_full());
+        assert_eq!(rb.view(), [1, 2]);
+    }
+}
@@ -0,0 +1,452 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use core::cmp::Ordering;
+
+use super::UVecMut;
+
+impl<'a, T> UVecMut<'a, T> {
+    /// Sorts the vector, see `sort_by`.
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use uvector::UVecMut;
+    ///
+    /// let mut vd: VecDeque<i32> = VecDeque::with_capacity(6);
+    /// vd.extend([5, 1, 4]);
+    /// vd.push_front(2);
+    /// vd.push_front(6);
+    /// UVecMut::new(vd.as_mut_slices()).sort();
+    /// assert_eq!(vd, [1, 2, 4, 5, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp)
+    }
+    /// Sorts the vector with a comparator function. The sort is stable. Each slice is sorted
+    /// with the slice `sort_by`, the slices are then merged in place without moving the data
+    /// into a contiguous buffer.
+    #[cfg(feature = "alloc")]
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (a, b) = self.range_mut(..).into_slices();
+        let mid = a.len();
+        a.sort_by(&mut compare);
+        b.sort_by(&mut compare);
+        merge(self, mid, &mut |x, y| compare(x, y) == Ordering::Less);
+    }
+    /// Sorts the vector with a key extraction function, see `sort_by`.
+    #[cfg(feature = "alloc")]
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)))
+    }
+    /// Sorts the vector without preserving the order of equal elements, see
+    /// `sort_unstable_by`.
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(T::cmp)
+    }
+    /// Sorts the vector with a comparator function without preserving the order of equal
+    /// elements. Each slice is sorted with the slice `sort_unstable_by`, the slices are then
+    /// merged in place. Doesn't allocate.
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (a, b) = self.range_mut(..).into_slices();
+        let mid = a.len();
+        a.sort_unstable_by(&mut compare);
+        b.sort_unstable_by(&mut compare);
+        merge(self, mid, &mut |x, y| compare(x, y) == Ordering::Less);
+    }
+    /// Sorts the vector with a key extraction function without preserving the order of equal
+    /// elements, see `sort_unstable_by`.
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_unstable_by(|a, b| f(a).cmp(&f(b)))
+    }
+    /// Reorders the vector such that the element at `index` is at its final sorted position,
+    /// see `select_nth_unstable_by`.
+    ///
+    /// ```
+    /// # use uvector::UVecMut;
+    /// let (a, b) = (&mut [9, 1, 8], &mut [2, 7, 3]);
+    /// let mut uv = UVecMut::new((a, b));
+    /// let (lo, median, hi) = uv.select_nth_unstable(2);
+    /// assert_eq!(*median, 3);
+    /// assert!(lo.iter().all(|&x| x <= 3) && hi.iter().all(|&x| x >= 3));
+    /// ```
+    pub fn select_nth_unstable(&mut self, index: usize) -> (UVecMut<'_, T>, &mut T, UVecMut<'_, T>)
+    where
+        T: Ord,
+    {
+        self.select_nth_unstable_by(index, T::cmp)
+    }
+    /// Reorders the vector with a comparator function such that the element at `index` is at
+    /// its final sorted position, all elements before it are less than or equal to it and all
+    /// elements after it are greater than or equal to it. Returns the elements before `index`,
+    /// the element at `index` and the elements after it.
+    ///
+    /// Uses quickselect, which takes `O(n)` time on average. If the pivots keep being poor it
+    /// switches to heapsort, so the worst case is `O(n log n)`. Doesn't allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than the length of the vector.
+    pub fn select_nth_unstable_by<F>(
+        &mut self,
+        index: usize,
+        mut compare: F,
+    ) -> (UVecMut<'_, T>, &mut T, UVecMut<'_, T>)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = self.len();
+        assert!(
+            index < len,
+            "partition_at_index index {} greater than length of slice {}",
+            index,
+            len
+        );
+        let limit = 2 * (usize::BITS - len.leading_zeros());
+        select(
+            self,
+            index,
+            &mut |x, y| compare(x, y) == Ordering::Less,
+            limit,
+        );
+        let (a, b) = self.range_mut(..).into_slices();
+        if index < a.len() {
+            let (lo, rest) = a.split_at_mut(index);
+            let (nth, hi) = rest.split_first_mut().unwrap();
+            (UVecMut::new((lo, &mut [])), nth, UVecMut::new((hi, b)))
+        } else {
+            let (lo, rest) = b.split_at_mut(index - a.len());
+            let (nth, hi) = rest.split_first_mut().unwrap();
+            (UVecMut::new((a, lo)), nth, UVecMut::new((hi, &mut [])))
+        }
+    }
+    /// Reorders the vector with a key extraction function such that the element at `index` is
+    /// at its final sorted position, see `select_nth_unstable_by`.
+    pub fn select_nth_unstable_by_key<K, F>(
+        &mut self,
+        index: usize,
+        mut f: F,
+    ) -> (UVecMut<'_, T>, &mut T, UVecMut<'_, T>)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.select_nth_unstable_by(index, |a, b| f(a).cmp(&f(b)))
+    }
+    /// Moves all consecutive repeated elements to the end of the vector. Returns the
+    /// deduplicated elements followed by the duplicates in no particular order, see
+    /// `partition_dedup_by`.
+    ///
+    /// ```
+    /// # use uvector::UVecMut;
+    /// let (a, b) = (&mut [1, 1, 2], &mut [2, 3, 1]);
+    /// let mut uv = UVecMut::new((a, b));
+    /// let (dedup, dups) = uv.partition_dedup();
+    /// assert_eq!(dedup.as_uvec(), [1, 2, 3, 1]);
+    /// assert_eq!(dups.len(), 2);
+    /// ```
+    pub fn partition_dedup(&mut self) -> (UVecMut<'_, T>, UVecMut<'_, T>)
+    where
+        T: PartialEq,
+    {
+        self.partition_dedup_by(|a, b| a == b)
+    }
+    /// Moves all but the first of consecutive elements for which `same_bucket` returns `true`
+    /// to the end of the vector. `same_bucket` is called with the current element and the
+    /// last retained element. Returns the retained elements in their original order followed
+    /// by the removed ones.
+    pub fn partition_dedup_by<F>(&mut self, mut same_bucket: F) -> (UVecMut<'_, T>, UVecMut<'_, T>)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let len = self.len();
+        let mut w = 1.min(len);
+        for r in 1..len {
+            let same = {
+                let (mut lo, mut hi) = self.split_at_mut(r);
+                same_bucket(&mut hi[0], &mut lo[w - 1])
+            };
+            if !same {
+                if r != w {
+                    self.swap(r, w);
+                }
+                w += 1;
+            }
+        }
+        self.split_at_mut(w)
+    }
+    /// Moves all but the first of consecutive elements that resolve to the same key to the
+    /// end of the vector, see `partition_dedup_by`.
+    pub fn partition_dedup_by_key<K, F>(&mut self, mut key: F) -> (UVecMut<'_, T>, UVecMut<'_, T>)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.partition_dedup_by(|a, b| key(a) == key(b))
+    }
+}
+
+/// Merges sorted runs `..mid` and `mid..` of `v` in place. Runs are split at the median of the
+/// longer one and the middle parts are swapped with a rotation, which takes `O(n log n)` moves
+/// and keeps equal elements in their original order.
+fn merge<T, F>(v: &mut UVecMut<T>, mid: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+    if mid == 0 || mid == len || !is_less(&v[mid], &v[mid - 1]) {
+        return;
+    }
+    if len == 2 {
+        return v.swap(0, 1);
+    }
+    let (cut1, cut2) = {
+        let u = v.as_uvec();
+        if mid >= len - mid {
+            let cut1 = mid / 2;
+            let x = &u[cut1];
+            (
+                cut1,
+                mid + u.range(mid..).partition_point(|y| is_less(y, x)),
+            )
+        } else {
+            let cut2 = mid + (len - mid) / 2;
+            let x = &u[cut2];
+            (u.range(..mid).partition_point(|y| !is_less(x, y)), cut2)
+        }
+    };
+    v.range_mut(cut1..cut2).rotate_left(mid - cut1);
+    let new_mid = cut1 + (cut2 - mid);
+    merge(&mut v.range_mut(..new_mid), cut1, is_less);
+    merge(&mut v.range_mut(new_mid..), cut2 - new_mid, is_less);
+}
+
+/// Moves the element that belongs at `index` in sorted order there using quickselect with
+/// Hoare partitioning. After `limit` partitioning rounds the remaining range is heapsorted
+/// instead, which bounds the time for inputs that defeat the median of three.
+fn select<T, F>(v: &mut UVecMut<T>, index: usize, is_less: &mut F, mut limit: u32)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let (mut lo, mut hi) = (0, v.len() - 1);
+    while lo < hi {
+        if limit == 0 {
+            return heapsort(&mut v.range_mut(lo..hi + 1), is_less);
+        }
+        limit -= 1;
+        // median of three as the pivot, moved to `lo`
+        let m = lo + (hi - lo) / 2;
+        if is_less(&v[m], &v[lo]) {
+            v.swap(m, lo);
+        }
+        if is_less(&v[hi], &v[m]) {
+            v.swap(hi, m);
+            if is_less(&v[m], &v[lo]) {
+                v.swap(m, lo);
+            }
+        }
+        v.swap(lo, m);
+        let (mut i, mut j) = (lo, hi + 1);
+        loop {
+            i += 1;
+            while i < hi && is_less(&v[i], &v[lo]) {
+                i += 1;
+            }
+            j -= 1;
+            while j > lo && is_less(&v[lo], &v[j]) {
+                j -= 1;
+            }
+            if i >= j {
+                break;
+            }
+            v.swap(i, j);
+        }
+        v.swap(lo, j);
+        match index.cmp(&j) {
+            Ordering::Equal => return,
+            Ordering::Less => hi = j - 1,
+            Ordering::Greater => lo = j + 1,
+        }
+    }
+}
+
+/// Sorts `v` with heapsort.
+fn heapsort<T, F>(v: &mut UVecMut<T>, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+    for i in (0..len / 2).rev() {
+        sift_down(v, i, len, is_less);
+    }
+    for end in (1..len).rev() {
+        v.swap(0, end);
+        sift_down(v, 0, end, is_less);
+    }
+}
+
+/// Restores the max-heap order of `v[..end]` below `node`.
+fn sift_down<T, F>(v: &mut UVecMut<T>, mut node: usize, end: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end && is_less(&v[child], &v[child + 1]) {
+            child += 1;
+        }
+        if !is_less(&v[node], &v[child]) {
+            return;
+        }
+        v.swap(node, child);
+        node = child;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::vec::Vec;
+
+    /// Deterministic pseudo-random numbers in `0..n`
+    fn numbers(count: usize, n: u32) -> Vec<u32> {
+        let mut x = 12345u32;
+        (0..count)
+            .map(|_| {
+                x = x.wrapping_mul(1103515245).wrapping_add(12345);
+                (x >> 16) % n
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sort_unstable() {
+        for &n in &[3, 1000] {
+            let data = numbers(100, n);
+            let mut sorted = data.clone();
+            sorted.sort();
+            for mid in 0..data.len() + 1 {
+                let mut v = data.clone();
+                let (a, b) = v.split_at_mut(mid);
+                UVecMut::new((a, b)).sort_unstable();
+                assert_eq!(v, sorted, "split at {}", mid);
+            }
+        }
+        let mut a = [3, 1];
+        let mut b = [2, 0];
+        let mut uv = UVecMut::new((&mut a, &mut b));
+        uv.sort_unstable_by_key(|&x| 10 - x);
+        assert_eq!(uv.as_uvec(), [3, 2, 1, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn sort_stable() {
+        let data: Vec<(u32, usize)> = numbers(60, 5).into_iter().zip(0..).collect();
+        let mut sorted = data.clone();
+        sorted.sort_by_key(|p| p.0);
+        for mid in 0..data.len() + 1 {
+            let mut v = data.clone();
+            let (a, b) = v.split_at_mut(mid);
+            UVecMut::new((a, b)).sort_by_key(|p| p.0);
+            assert_eq!(v, sorted, "split at {}", mid);
+        }
+        let mut a = [2, 1];
+        let mut b = [3];
+        UVecMut::new((&mut a, &mut b)).sort();
+        assert_eq!((a, b), ([1, 2], [3]));
+    }
+
+    #[test]
+    fn select_nth() {
+        for &n in &[2, 1000] {
+            let data = numbers(50, n);
+            let mut sorted = data.clone();
+            sorted.sort();
+            for mid in [0, 1, 25, 49, 50] {
+                for (index, &expected) in sorted.iter().enumerate() {
+                    let mut v = data.clone();
+                    let (a, b) = v.split_at_mut(mid);
+                    let mut uv = UVecMut::new((a, b));
+                    let (lo, nth, hi) = uv.select_nth_unstable(index);
+                    assert_eq!(*nth, expected);
+                    assert_eq!(lo.len(), index);
+                    assert!(lo.iter().all(|x| *x <= expected));
+                    assert!(hi.iter().all(|x| *x >= expected));
+                }
+            }
+        }
+        let mut a = [1];
+        let mut uv = UVecMut::new((&mut a, &mut []));
+        assert_eq!(*uv.select_nth_unstable_by_key(0, |&x| x).1, 1);
+    }
+
+    #[test]
+    fn select_heapsort_fallback() {
+        let data = numbers(40, 30);
+        let mut sorted = data.clone();
+        sorted.sort();
+        for limit in 0..3 {
+            for index in 0..data.len() {
+                let mut v = data.clone();
+                let (a, b) = v.split_at_mut(15);
+                select(&mut UVecMut::new((a, b)), index, &mut |x, y| x < y, limit);
+                assert_eq!(v[index], sorted[index]);
+                assert!(v[..index].iter().all(|&x| x <= sorted[index]));
+                assert!(v[index..].iter().all(|&x| x >= sorted[index]));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "partition_at_index index 2 greater than length of slice 2")]
+    fn select_nth_out_of_range() {
+        let (mut a, mut b) = ([1], [2]);
+        UVecMut::new((&mut a, &mut b)).select_nth_unstable(2);
+    }
+
+    #[test]
+    fn partition_dedup() {
+        let data = [1, 1, 2, 3, 3, 3, 1, 4, 4];
+        for mid in 0..data.len() + 1 {
+            let mut v = data;
+            let (a, b) = v.split_at_mut(mid);
+            let mut uv = UVecMut::new((a, b));
+            let (dedup, dups) = uv.partition_dedup();
+            assert_eq!(dedup.as_uvec(), [1, 2, 3, 1, 4]);
+            let mut dups: Vec<i32> = dups.iter().copied().collect();
+            dups.sort();
+            assert_eq!(dups, [1, 3, 3, 4]);
+        }
+        let mut a = [10, 11, 20];
+        let mut b = [25, 31];
+        let mut uv = UVecMut::new((&mut a, &mut b));
+        let (dedup, _) = uv.partition_dedup_by_key(|x| *x / 10);
+        assert_eq!(dedup.as_uvec(), [10, 20, 31]);
+        assert_eq!(UVecMut::<i32>::empty().partition_dedup().0.len(), 0);
+    }
+}
@@ -0,0 +1,380 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::slice;
+
+#[cfg(not(loom))]
+use alloc::sync::Arc;
+#[cfg(not(loom))]
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(loom)]
+use loom::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(loom)]
+use loom::sync::Arc;
+
+use super::{UVec, UVecMut};
+
+/// Creates a lock-free single-producer single-consumer ring buffer that can hold `capacity`
+/// values. The values are initialized with `T::default()` and are reused as the ring wraps
+/// around, they are only dropped together with the ring.
+///
+/// # Panics
+///
+/// Panics if `capacity` is 0 or greater than `usize::MAX / 2`.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+///
+/// let (mut tx, mut rx) = uvector::spsc::<u32>(4);
+/// let t = thread::spawn(move || {
+///     let mut next = 0;
+///     while next < 100 {
+///         let mut w = tx.writable();
+///         let n = w.len().min(100 - next as usize);
+///         for (i, slot) in w.iter_mut().take(n).enumerate() {
+///             *slot = next + i as u32;
+///         }
+///         tx.commit(n);
+///         next += n as u32;
+///     }
+/// });
+/// let mut sum = 0;
+/// let mut received = 0;
+/// while received < 100 {
+///     let r = rx.readable();
+///     sum += r.iter().sum::<u32>();
+///     let n = r.len();
+///     rx.consume(n);
+///     received += n;
+/// }
+/// t.join().unwrap();
+/// assert_eq!(sum, (0..100).sum());
+/// ```
+pub fn spsc<T: Default>(capacity: usize) -> (Producer<T>, Consumer<T>) {
+    assert!(capacity != 0, "capacity must be non-zero");
+    assert!(capacity <= usize::MAX / 2, "capacity overflow");
+    let buf: Vec<UnsafeCell<T>> = (0..capacity)
+        .map(|_| UnsafeCell::new(T::default()))
+        .collect();
+    let shared = Arc::new(Shared {
+        buf: buf.into_boxed_slice(),
+        #[cfg(loom)]
+        access: (0..capacity)
+            .map(|_| loom::cell::UnsafeCell::new(()))
+            .collect(),
+        head: AtomicUsize::new(0),
+        tail: AtomicUsize::new(0),
+    });
+    (
+        Producer {
+            shared: shared.clone(),
+            tail: 0,
+        },
+        Consumer { shared, head: 0 },
+    )
+}
+
+struct Shared<T> {
+    buf: Box<[UnsafeCell<T>]>,
+    /// Tracks accesses to the values, so `loom` can detect races on them
+    #[cfg(loom)]
+    access: Box<[loom::cell::UnsafeCell<()>]>,
+    /// Position of the first value not consumed yet, only written by the consumer
+    head: AtomicUsize,
+    /// Position after the last committed value, only written by the producer
+    tail: AtomicUsize,
+}
+
+/// Positions run over `0..2 * capacity`, so a full ring can be told from an empty one and a
+/// position always maps to the same slot, even when it wraps.
+impl<T> Shared<T> {
+    /// Returns the number of values between positions `head` and `tail`.
+    fn distance(&self, head: usize, tail: usize) -> usize {
+        if tail >= head {
+            tail - head
+        } else {
+            tail + (2 * self.buf.len() - head)
+        }
+    }
+    /// Returns the position `n` values after `pos`.
+    fn advance(&self, pos: usize, n: usize) -> usize {
+        let left = 2 * self.buf.len() - pos;
+        if n >= left {
+            n - left
+        } else {
+            pos + n
+        }
+    }
+    /// Returns the start and the length of the first part of `len` values at position `pos`,
+    /// the second part starts at the beginning of the buffer.
+    fn split(&self, pos: usize, len: usize) -> (usize, usize) {
+        let cap = self.buf.len();
+        let start = if pos >= cap { pos - cap } else { pos };
+        (start, len.min(cap - start))
+    }
+    /// Returns `len` values starting at position `pos` as two mutable slices.
+    ///
+    /// # Safety
+    ///
+    /// The caller must have exclusive access to these values for the returned lifetime.
+    #[allow(clippy::mut_from_ref)]
+    unsafe fn slices_mut(&self, pos: usize, len: usize) -> (&mut [T], &mut [T]) {
+        let (start, first) = self.split(pos, len);
+        #[cfg(loom)]
+        for i in 0..len {
+            self.access[(start + i) % self.buf.len()].with_mut(|_| ());
+        }
+        // `UnsafeCell<T>` has the same memory layout as `T`
+        let base = UnsafeCell::raw_get(self.buf.as_ptr());
+        (
+            slice::from_raw_parts_mut(base.add(start), first),
+            slice::from_raw_parts_mut(base, len - first),
+        )
+    }
+    /// Returns `len` values starting at position `pos` as two slices.
+    ///
+    /// # Safety
+    ///
+    /// The values must not be modified for the returned lifetime.
+    unsafe fn slices(&self, pos: usize, len: usize) -> (&[T], &[T]) {
+        let (start, first) = self.split(pos, len);
+        #[cfg(loom)]
+        for i in 0..len {
+            self.access[(start + i) % self.buf.len()].with(|_| ());
+        }
+        let base = UnsafeCell::raw_get(self.buf.as_ptr()) as *const T;
+        (
+            slice::from_raw_parts(base.add(start), first),
+            slice::from_raw_parts(base, len - first),
+        )
+    }
+}
+
+/// The writing half of a ring created by `spsc`
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+    tail: usize,
+}
+
+// SAFETY: the producer only accesses the values that are not visible to the consumer
+unsafe impl<T: Send> Send for Producer<T> {}
+
+impl<T> Producer<T> {
+    /// Returns the number of values the ring can hold.
+    pub fn capacity(&self) -> usize {
+        self.shared.buf.len()
+    }
+    /// Returns a mutable view of the free space of the ring. The values written into it become
+    /// visible to the consumer after `commit`.
+    pub fn writable(&mut self) -> UVecMut<'_, T> {
+        let head = self.shared.head.load(Ordering::Acquire);
+        let free = self.capacity() - self.shared.distance(head, self.tail);
+        // SAFETY: the consumer doesn't access values after `head + len`, the acquire load makes
+        // sure it has finished reading the values it has consumed
+        UVecMut::new(unsafe { self.shared.slices_mut(self.tail, free) })
+    }
+    /// Makes the first `n` values of `writable` visible to the consumer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the free space of the ring.
+    pub fn commit(&mut self, n: usize) {
+        let head = self.shared.head.load(Ordering::Acquire);
+        let free = self.capacity() - self.shared.distance(head, self.tail);
+        assert!(
+            n <= free,
+            "commit of {} values exceeds free space of {}",
+            n,
+            free
+        );
+        self.tail = self.shared.advance(self.tail, n);
+        self.shared.tail.store(self.tail, Ordering::Release);
+    }
+}
+
+/// The reading half of a ring created by `spsc`
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+    head: usize,
+}
+
+// SAFETY: the consumer only accesses the values committed by the producer
+unsafe impl<T: Send> Send for Consumer<T> {}
+
+impl<T> Consumer<T> {
+    /// Returns the number of values the ring can hold.
+    pub fn capacity(&self) -> usize {
+        self.shared.buf.len()
+    }
+    /// Returns a view of the values committed by the producer and not consumed yet.
+    pub fn readable(&self) -> UVec<'_, T> {
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        let len = self.shared.distance(self.head, tail);
+        // SAFETY: the producer doesn't access committed values until they are consumed, the
+        // acquire load makes sure its writes are visible
+        UVec::new(unsafe { self.shared.slices(self.head, len) })
+    }
+    /// Removes the first `n` values of `readable` making space for the producer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the number of readable values.
+    pub fn consume(&mut self, n: usize) {
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        let len = self.shared.distance(self.head, tail);
+        assert!(n <= len, "consume of {} values exceeds length {}", n, len);
+        self.head = self.shared.advance(self.head, n);
+        self.shared.head.store(self.head, Ordering::Release);
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod test {
+    use super::*;
+    use std::thread;
+
+    /// Creates an empty ring with both positions set to `pos`.
+    fn spsc_at<T: Default>(capacity: usize, pos: usize) -> (Producer<T>, Consumer<T>) {
+        let (mut tx, mut rx) = spsc(capacity);
+        tx.shared.head.store(pos, Ordering::Relaxed);
+        tx.shared.tail.store(pos, Ordering::Relaxed);
+        tx.tail = pos;
+        rx.head = pos;
+        (tx, rx)
+    }
+
+    #[test]
+    fn position_wrap() {
+        let (mut tx, mut rx) = spsc_at::<u32>(7, 2 * 7 - 3);
+        let (mut next, mut expected) = (0, 0);
+        for round in 0..50 {
+            let mut w = tx.writable();
+            assert_eq!(w.len() + rx.readable().len(), 7);
+            // the writable view must not overlap the values still readable
+            w.fill(u32::MAX);
+            let n = (round * 3) % (w.len() + 1);
+            for v in w.iter_mut().take(n) {
+                *v = next;
+                next += 1;
+            }
+            tx.commit(n);
+            let r = rx.readable();
+            assert!(r.iter().copied().eq(expected..next));
+            let k = r.len().min(round % 4);
+            rx.consume(k);
+            expected += k as u32;
+        }
+        assert!(next > 30);
+    }
+
+    #[test]
+    fn wrap_around() {
+        let (mut tx, mut rx) = spsc::<i32>(4);
+        assert_eq!(tx.writable().len(), 4);
+        tx.writable().copy_from_slice(&[1, 2, 3, 4]);
+        tx.commit(3);
+        assert_eq!(rx.readable(), [1, 2, 3]);
+        rx.consume(2);
+        let mut w = tx.writable();
+        assert_eq!(w.len(), 3);
+        w.copy_from_slice(&[5, 6, 7]);
+        assert!(w.as_single_slice().is_none());
+        tx.commit(3);
+        let r = rx.readable();
+        assert_eq!(r, [3, 5, 6, 7]);
+        assert!(r.as_single_slice().is_none());
+        rx.consume(4);
+        assert!(rx.readable().is_empty());
+        assert_eq!(rx.capacity(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "commit of 3 values exceeds free space of 2")]
+    fn commit_too_much() {
+        let (mut tx, _rx) = spsc::<u8>(2);
+        tx.commit(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "consume of 1 values exceeds length 0")]
+    fn consume_too_much() {
+        let (_tx, mut rx) = spsc::<u8>(2);
+        rx.consume(1);
+    }
+
+    #[test]
+    fn threads() {
+        const N: u64 = 100_000;
+        let (mut tx, mut rx) = spsc::<u64>(7);
+        let t = thread::spawn(move || {
+            let mut next = 0;
+            while next < N {
+                let mut w = tx.writable();
+                let n = w.len().min((N - next) as usize);
+                for (i, v) in w.iter_mut().take(n).enumerate() {
+                    *v = next + i as u64;
+                }
+                tx.commit(n);
+                next += n as u64;
+                if n == 0 {
+                    thread::yield_now();
+                }
+            }
+        });
+        let mut expected = 0;
+        while expected < N {
+            let r = rx.readable();
+            for &v in r.iter() {
+                assert_eq!(v, expected);
+                expected += 1;
+            }
+            let n = r.len();
+            rx.consume(n);
+            if n == 0 {
+                thread::yield_now();
+            }
+        }
+        t.join().unwrap();
+    }
+}
+
+/// Run with `RUSTFLAGS="--cfg loom" cargo test --release --lib spsc`
+#[cfg(all(test, loom))]
+mod loom_test {
+    use super::*;
+    use loom::thread;
+
+    #[test]
+    fn transfer() {
+        loom::model(|| {
+            let (mut tx, mut rx) = spsc::<usize>(2);
+            let t = thread::spawn(move || {
+                for i in 1..4 {
+                    let mut w = tx.writable();
+                    if w.is_empty() {
+                        break;
+                    }
+                    w[0] = i;
+                    tx.commit(1);
+                }
+            });
+            let mut expected = 1;
+            for _ in 0..2 {
+                let r = rx.readable();
+                for &v in r.iter() {
+                    assert_eq!(v, expected);
+                    expected += 1;
+                }
+                let n = r.len();
+                rx.consume(n);
+            }
+            t.join().unwrap();
+        });
+    }
+}
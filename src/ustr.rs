@@ -0,0 +1,457 @@
+// Copyright 2017 Pavel Shaydo
+//
+// Licensed under the MIT license see LICENSE file
+
+use core::fmt;
+use core::iter::{Chain, FusedIterator};
+use core::ops::RangeBounds;
+use core::option;
+use core::str;
+
+use super::range;
+use super::UVec;
+
+/// Read-only string type allowing access two byte slices containing UTF-8 text as a single
+/// string.
+///
+/// A character may be split between the two slices, e.g. when the text is buffered in a
+/// `VecDeque<u8>` that wraps around in the middle of a multi-byte character.
+///
+/// # Examples
+///
+/// ```
+/// use uvector::{UStr, UVec};
+///
+/// let us = UStr::from_utf8(UVec::new((b"caf\xc3", b"\xa9\nbar"))).unwrap();
+/// assert_eq!(us.chars().nth(3), Some('é'));
+/// assert_eq!(us.lines().count(), 2);
+/// assert_eq!(us.find("bar"), Some(6));
+/// assert_eq!(us.to_string(), "café\nbar");
+/// ```
+#[derive(Debug, Clone)]
+pub struct UStr<'a> {
+    head: &'a str,
+    mid: Option<char>,
+    tail: &'a str,
+    bytes: UVec<'a, u8>,
+}
+
+impl<'a> UStr<'a> {
+    /// Constructs a new `UStr` from a tupple of two string slices
+    pub fn new(s: (&'a str, &'a str)) -> Self {
+        UStr {
+            head: s.0,
+            mid: None,
+            tail: s.1,
+            bytes: UVec::new((s.0.as_bytes(), s.1.as_bytes())),
+        }
+    }
+    /// Converts a `UVec<u8>` into a `UStr` checking that it contains valid UTF-8. A character
+    /// may start in the first slice and end in the second one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Utf8Error` with the position of the invalid sequence counted from the start of
+    /// the vector.
+    ///
+    /// ```
+    /// # use uvector::{UStr, UVec};
+    /// let err = UStr::from_utf8(UVec::new((b"ab", b"c\xffd"))).unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 3);
+    /// assert_eq!(err.error_len(), Some(1));
+    /// ```
+    pub fn from_utf8(bytes: UVec<'a, u8>) -> Result<Self, Utf8Error> {
+        let (a, b) = bytes.s;
+        let (head, mid, rest) = match str::from_utf8(a) {
+            Ok(head) => (head, None, b),
+            Err(e) => {
+                let k = e.valid_up_to();
+                if e.error_len().is_some() {
+                    return Err(Utf8Error::from_core(0, e));
+                }
+                // the first slice ends in the middle of a character, complete it with the bytes
+                // from the start of the second slice
+                let partial = &a[k..];
+                let need = (!partial[0]).leading_zeros() as usize - partial.len();
+                let take = need.min(b.len());
+                let mut buf = [0u8; 4];
+                buf[..partial.len()].copy_from_slice(partial);
+                buf[partial.len()..partial.len() + take].copy_from_slice(&b[..take]);
+                let c = match str::from_utf8(&buf[..partial.len() + take]) {
+                    Ok(s) if take == need => s.chars().next(),
+                    Ok(_) => return Err(Utf8Error::new(k, None)),
+                    Err(e) => return Err(Utf8Error::from_core(k, e)),
+                };
+                // SAFETY: `from_utf8` has validated the bytes up to `k`
+                let head = unsafe { str::from_utf8_unchecked(&a[..k]) };
+                (head, c, &b[need..])
+            }
+        };
+        let offset = bytes.len() - rest.len();
+        let tail = str::from_utf8(rest).map_err(|e| Utf8Error::from_core(offset, e))?;
+        Ok(UStr {
+            head,
+            mid,
+            tail,
+            bytes,
+        })
+    }
+    /// Returns the length of the string in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+    /// Returns `true` if the string has a length of zero bytes.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+    /// Returns the content of the string as bytes.
+    pub fn as_bytes(&self) -> UVec<'a, u8> {
+        self.bytes
+    }
+    /// Returns a new `UStr` that only includes the specified range of bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is not contained within the string, or if its start or end is not
+    /// on a character boundary.
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        let r = match range::bounds(range, self.len()) {
+            Ok(r) => r,
+            Err(e) => panic!("{}", e),
+        };
+        let h = self.head.len();
+        let t = h + self.mid.map_or(0, char::len_utf8);
+        for &i in &[r.start, r.end] {
+            assert!(i <= h || i >= t, "byte index {} is not a char boundary", i);
+        }
+        UStr {
+            head: &self.head[r.start.min(h)..r.end.min(h)],
+            mid: self.mid.filter(|_| r.start <= h && r.end >= t),
+            tail: &self.tail[r.start.saturating_sub(t)..r.end.saturating_sub(t)],
+            bytes: self.bytes.range(r),
+        }
+    }
+    /// Returns an iterator over the characters of the string.
+    pub fn chars(&self) -> Chars<'a> {
+        Chars {
+            inner: self.head.chars().chain(self.mid).chain(self.tail.chars()),
+        }
+    }
+    /// Returns an iterator over the characters of the string and their byte positions.
+    pub fn char_indices(&self) -> CharIndices<'a> {
+        CharIndices {
+            chars: self.chars(),
+            front: 0,
+            back: self.len(),
+        }
+    }
+    /// Returns an iterator over the lines of the string. Lines are terminated with `\n` or
+    /// `\r\n`, the line terminators are not included, the final line ending is optional.
+    pub fn lines(&self) -> Lines<'a> {
+        Lines { rest: self.clone() }
+    }
+    /// Returns an iterator over the substrings of the string separated by `sep`.
+    ///
+    /// ```
+    /// # use uvector::UStr;
+    /// let us = UStr::new(("a,b", ",,c"));
+    /// let parts: Vec<String> = us.split(',').map(|s| s.to_string()).collect();
+    /// assert_eq!(parts, ["a", "b", "", "c"]);
+    /// ```
+    pub fn split(&self, sep: char) -> Split<'a> {
+        let mut sep_buf = [0u8; 4];
+        let sep_len = sep.encode_utf8(&mut sep_buf).len();
+        Split {
+            rest: Some(self.clone()),
+            sep_buf,
+            sep_len,
+        }
+    }
+    /// Returns the byte index of the first occurrence of `pat` in the string.
+    pub fn find(&self, pat: &str) -> Option<usize> {
+        self.bytes.find_subslice(pat.as_bytes())
+    }
+}
+
+impl<'a> fmt::Display for UStr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.head)?;
+        if let Some(c) = self.mid {
+            fmt::Write::write_char(f, c)?;
+        }
+        f.write_str(self.tail)
+    }
+}
+
+impl<'a> PartialEq<str> for UStr<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.bytes == *other.as_bytes()
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for UStr<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        self.bytes == *other.as_bytes()
+    }
+}
+
+/// The error returned when a `UVec<u8>` doesn't contain valid UTF-8, see `UStr::from_utf8`
+///
+/// Same as `core::str::Utf8Error`, but the positions are counted from the start of the vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf8Error {
+    valid_up_to: usize,
+    error_len: Option<u8>,
+}
+
+impl Utf8Error {
+    fn new(valid_up_to: usize, error_len: Option<u8>) -> Self {
+        Utf8Error {
+            valid_up_to,
+            error_len,
+        }
+    }
+    /// Converts an error for a slice starting at `offset` in the vector
+    fn from_core(offset: usize, e: str::Utf8Error) -> Self {
+        Utf8Error::new(offset + e.valid_up_to(), e.error_len().map(|n| n as u8))
+    }
+    /// Returns the index up to which the vector contains valid UTF-8.
+    pub fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+    /// Returns the length of the invalid byte sequence, or `None` if the vector ends in the
+    /// middle of a character.
+    pub fn error_len(&self) -> Option<usize> {
+        self.error_len.map(|n| n as usize)
+    }
+}
+
+impl fmt::Display for Utf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.error_len {
+            Some(n) => write!(
+                f,
+                "invalid utf-8 sequence of {} bytes from index {}",
+                n, self.valid_up_to
+            ),
+            None => write!(
+                f,
+                "incomplete utf-8 byte sequence from index {}",
+                self.valid_up_to
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Utf8Error {}
+
+/// An iterator over the characters of a `UStr`, see `UStr::chars`
+#[derive(Debug, Clone)]
+pub struct Chars<'a> {
+    inner: Chain<Chain<str::Chars<'a>, option::IntoIter<char>>, str::Chars<'a>>,
+}
+
+impl<'a> Iterator for Chars<'a> {
+    type Item = char;
+    fn next(&mut self) -> Option<char> {
+        self.inner.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Chars<'a> {
+    fn next_back(&mut self) -> Option<char> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a> FusedIterator for Chars<'a> {}
+
+/// An iterator over the characters of a `UStr` and their byte positions, see
+/// `UStr::char_indices`
+#[derive(Debug, Clone)]
+pub struct CharIndices<'a> {
+    chars: Chars<'a>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Iterator for CharIndices<'a> {
+    type Item = (usize, char);
+    fn next(&mut self) -> Option<(usize, char)> {
+        let c = self.chars.next()?;
+        let i = self.front;
+        self.front += c.len_utf8();
+        Some((i, c))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for CharIndices<'a> {
+    fn next_back(&mut self) -> Option<(usize, char)> {
+        let c = self.chars.next_back()?;
+        self.back -= c.len_utf8();
+        Some((self.back, c))
+    }
+}
+
+impl<'a> FusedIterator for CharIndices<'a> {}
+
+/// An iterator over the lines of a `UStr`, see `UStr::lines`
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    rest: UStr<'a>,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = UStr<'a>;
+    fn next(&mut self) -> Option<UStr<'a>> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        match self.rest.bytes.find_byte(b'\n') {
+            Some(i) => {
+                let line = self.rest.range(..i);
+                self.rest = self.rest.range(i + 1..);
+                match line.bytes.last() {
+                    Some(&b'\r') => Some(line.range(..i - 1)),
+                    _ => Some(line),
+                }
+            }
+            None => {
+                let line = self.rest.clone();
+                self.rest = self.rest.range(line.len()..);
+                Some(line)
+            }
+        }
+    }
+}
+
+impl<'a> FusedIterator for Lines<'a> {}
+
+/// An iterator over the substrings of a `UStr` separated by a character, see `UStr::split`
+#[derive(Debug, Clone)]
+pub struct Split<'a> {
+    rest: Option<UStr<'a>>,
+    sep_buf: [u8; 4],
+    sep_len: usize,
+}
+
+impl<'a> Iterator for Split<'a> {
+    type Item = UStr<'a>;
+    fn next(&mut self) -> Option<UStr<'a>> {
+        let rest = self.rest.take()?;
+        match rest.bytes.find_subslice(&self.sep_buf[..self.sep_len]) {
+            Some(i) => {
+                self.rest = Some(rest.range(i + self.sep_len..));
+                Some(rest.range(..i))
+            }
+            None => Some(rest),
+        }
+    }
+}
+
+impl<'a> FusedIterator for Split<'a> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+
+    fn all_splits(s: &str) -> Vec<UStr<'_>> {
+        let b = s.as_bytes();
+        (0..b.len() + 1)
+            .map(|mid| UStr::from_utf8(UVec::new(b.split_at(mid))).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn from_utf8() {
+        let s = "añ€😀z";
+        for us in all_splits(s) {
+            assert_eq!(us.to_string(), s);
+            assert_eq!(us.chars().collect::<String>(), s);
+            assert_eq!(us.chars().rev().collect::<String>(), "z😀€ña");
+            assert_eq!(us, s);
+        }
+    }
+
+    #[test]
+    fn from_utf8_error() {
+        let check = |a: &[u8], b: &[u8], valid_up_to, error_len| {
+            let err = UStr::from_utf8(UVec::new((a, b))).unwrap_err();
+            assert_eq!(err.valid_up_to(), valid_up_to);
+            assert_eq!(err.error_len(), error_len);
+        };
+        check(b"a\xff", b"", 1, Some(1));
+        check(b"a", b"b\xff", 2, Some(1));
+        check(b"a\xe2\x82", b"", 1, None);
+        check(b"a\xe2", b"\x82", 1, None);
+        check(b"a\xe2", b"\x82z", 1, Some(2));
+        check(b"a\xe2", b"z", 1, Some(1));
+        check(b"a\xf0\x9f", b"\x98\x80\xe2", 5, None);
+        check(b"", b"\xe2\x82", 0, None);
+        let err = UStr::from_utf8(UVec::new((b"ab\xe2", b"x"))).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid utf-8 sequence of 1 bytes from index 2"
+        );
+    }
+
+    #[test]
+    fn char_indices() {
+        let s = "a€b";
+        for us in all_splits(s) {
+            let v: Vec<_> = us.char_indices().collect();
+            assert_eq!(v, s.char_indices().collect::<Vec<_>>());
+            let v: Vec<_> = us.char_indices().rev().collect();
+            assert_eq!(v, s.char_indices().rev().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn lines_split() {
+        for s in ["one\r\ntwö\n\nthree\n", "abc\r", "a\r\rb\r\n\r"] {
+            for us in all_splits(s) {
+                let lines: Vec<String> = us.lines().map(|l| l.to_string()).collect();
+                assert_eq!(lines, s.lines().collect::<Vec<_>>());
+                let parts: Vec<String> = us.split('ö').map(|l| l.to_string()).collect();
+                assert_eq!(parts, s.split('ö').collect::<Vec<_>>());
+                assert_eq!(us.find("three"), s.find("three"));
+                assert_eq!(us.find("four"), None);
+            }
+        }
+        assert_eq!(UStr::new(("", "")).lines().count(), 0);
+        assert_eq!(UStr::new(("", "")).split(',').count(), 1);
+    }
+
+    #[test]
+    fn range() {
+        let s = "a€b";
+        for us in all_splits(s) {
+            assert_eq!(us.range(1..4), "€");
+            assert_eq!(us.range(1..4).to_string(), "€");
+            assert_eq!(us.range(..1).to_string(), "a");
+            assert_eq!(us.range(4..).chars().collect::<String>(), "b");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a char boundary")]
+    fn range_not_boundary() {
+        UStr::new(("a€", "b")).range(2..);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a char boundary")]
+    fn range_not_boundary_split() {
+        let us = UStr::from_utf8(UVec::new("a€".as_bytes().split_at(2))).unwrap();
+        us.range(..3);
+    }
+}
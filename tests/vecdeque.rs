@@ -1,8 +1,9 @@
 extern crate uvector;
 
 use std::collections::VecDeque;
-use uvector::UVec;
+use uvector::{UVec, UVecMut};
 
+#[allow(clippy::unnecessary_fold)]
 fn check_sum(vd: &VecDeque<i32>, exp: i32) {
     let uv = UVec::new(vd.as_slices());
     let sum = uv.iter().fold(0, |sum, x| sum + x);
@@ -23,3 +24,18 @@ fn vecdeque() {
     vd.push_back(7);
     check_sum(&vd, 22);
 }
+
+#[test]
+fn vecdeque_mut() {
+    let mut vd: VecDeque<i32> = VecDeque::new();
+    for i in 1i32..4 {
+        vd.push_back(i);
+        vd.push_front(-i);
+    }
+    {
+        let mut uv = UVecMut::new(vd.as_mut_slices());
+        uv.rotate_left(3);
+        uv.swap(0, 5);
+    }
+    assert_eq!(vd, [-1, 2, 3, -3, -2, 1]);
+}